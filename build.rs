@@ -1,131 +1,2156 @@
+#[cfg(feature = "buildtime-bindgen")]
 extern crate bindgen;
+extern crate cc;
 extern crate cmake;
 extern crate pkg_config;
+#[cfg(feature = "vcpkg")]
+extern crate vcpkg;
 extern crate walkdir;
+extern crate which;
 
 use cmake::Config;
 use std::env;
+use std::path::Path;
+
+// Every importer that can be toggled with an `importer-<name>` feature, along with the CMake
+// option that controls it. Assimp turns a disabled option into `ASSIMP_BUILD_NO_<X>_IMPORTER`.
+const IMPORTERS: &[(&str, &str)] = &[
+    ("amf", "ASSIMP_BUILD_AMF_IMPORTER"),
+    ("3ds", "ASSIMP_BUILD_3DS_IMPORTER"),
+    ("ac", "ASSIMP_BUILD_AC_IMPORTER"),
+    ("ase", "ASSIMP_BUILD_ASE_IMPORTER"),
+    ("assbin", "ASSIMP_BUILD_ASSBIN_IMPORTER"),
+    ("b3d", "ASSIMP_BUILD_B3D_IMPORTER"),
+    ("bvh", "ASSIMP_BUILD_BVH_IMPORTER"),
+    ("collada", "ASSIMP_BUILD_COLLADA_IMPORTER"),
+    ("dxf", "ASSIMP_BUILD_DXF_IMPORTER"),
+    ("csm", "ASSIMP_BUILD_CSM_IMPORTER"),
+    ("hmp", "ASSIMP_BUILD_HMP_IMPORTER"),
+    ("irrmesh", "ASSIMP_BUILD_IRRMESH_IMPORTER"),
+    ("irr", "ASSIMP_BUILD_IRR_IMPORTER"),
+    ("lwo", "ASSIMP_BUILD_LWO_IMPORTER"),
+    ("lws", "ASSIMP_BUILD_LWS_IMPORTER"),
+    ("md2", "ASSIMP_BUILD_MD2_IMPORTER"),
+    ("md3", "ASSIMP_BUILD_MD3_IMPORTER"),
+    ("md5", "ASSIMP_BUILD_MD5_IMPORTER"),
+    ("mdc", "ASSIMP_BUILD_MDC_IMPORTER"),
+    ("mdl", "ASSIMP_BUILD_MDL_IMPORTER"),
+    ("nff", "ASSIMP_BUILD_NFF_IMPORTER"),
+    ("ndo", "ASSIMP_BUILD_NDO_IMPORTER"),
+    ("off", "ASSIMP_BUILD_OFF_IMPORTER"),
+    ("obj", "ASSIMP_BUILD_OBJ_IMPORTER"),
+    ("ogre", "ASSIMP_BUILD_OGRE_IMPORTER"),
+    ("opengex", "ASSIMP_BUILD_OPENGEX_IMPORTER"),
+    ("ply", "ASSIMP_BUILD_PLY_IMPORTER"),
+    ("ms3d", "ASSIMP_BUILD_MS3D_IMPORTER"),
+    ("cob", "ASSIMP_BUILD_COB_IMPORTER"),
+    ("blend", "ASSIMP_BUILD_BLEND_IMPORTER"),
+    ("ifc", "ASSIMP_BUILD_IFC_IMPORTER"),
+    ("xgl", "ASSIMP_BUILD_XGL_IMPORTER"),
+    ("fbx", "ASSIMP_BUILD_FBX_IMPORTER"),
+    ("q3d", "ASSIMP_BUILD_Q3D_IMPORTER"),
+    ("q3bsp", "ASSIMP_BUILD_Q3BSP_IMPORTER"),
+    ("raw", "ASSIMP_BUILD_RAW_IMPORTER"),
+    ("sib", "ASSIMP_BUILD_SIB_IMPORTER"),
+    ("smd", "ASSIMP_BUILD_SMD_IMPORTER"),
+    ("stl", "ASSIMP_BUILD_STL_IMPORTER"),
+    ("terragen", "ASSIMP_BUILD_TERRAGEN_IMPORTER"),
+    ("3d", "ASSIMP_BUILD_3D_IMPORTER"),
+    ("x", "ASSIMP_BUILD_X_IMPORTER"),
+    ("x3d", "ASSIMP_BUILD_X3D_IMPORTER"),
+    ("gltf", "ASSIMP_BUILD_GLTF_IMPORTER"),
+    ("3mf", "ASSIMP_BUILD_3MF_IMPORTER"),
+    ("mmd", "ASSIMP_BUILD_MMD_IMPORTER"),
+];
+
+// Same as `IMPORTERS`, but for `exporter-<name>` features.
+const EXPORTERS: &[(&str, &str)] = &[
+    ("3ds", "ASSIMP_BUILD_3DS_EXPORTER"),
+    ("assbin", "ASSIMP_BUILD_ASSBIN_EXPORTER"),
+    ("assxml", "ASSIMP_BUILD_ASSXML_EXPORTER"),
+    ("assjson", "ASSIMP_BUILD_ASSJSON_EXPORTER"),
+    ("collada", "ASSIMP_BUILD_COLLADA_EXPORTER"),
+    ("fbx", "ASSIMP_BUILD_FBX_EXPORTER"),
+    ("gltf", "ASSIMP_BUILD_GLTF_EXPORTER"),
+    ("obj", "ASSIMP_BUILD_OBJ_EXPORTER"),
+    ("opengex", "ASSIMP_BUILD_OPENGEX_EXPORTER"),
+    ("ply", "ASSIMP_BUILD_PLY_EXPORTER"),
+    ("step", "ASSIMP_BUILD_STEP_EXPORTER"),
+    ("stl", "ASSIMP_BUILD_STL_EXPORTER"),
+    ("x", "ASSIMP_BUILD_X_EXPORTER"),
+    ("x3d", "ASSIMP_BUILD_X3D_EXPORTER"),
+    ("3mf", "ASSIMP_BUILD_3MF_EXPORTER"),
+];
+
+// Environment set by cargo for build scripts that the build depends on, see `main`.
+const CARGO_ENV: &[&str] = &[
+    "OUT_DIR",
+    "CARGO_MANIFEST_DIR",
+    "TARGET",
+    "HOST",
+    "PROFILE",
+    "OPT_LEVEL",
+    "DEBUG",
+    "CARGO_CFG_TARGET_OS",
+    "CARGO_CFG_TARGET_ENV",
+];
+
+// The assimp versions the C API of the bindings is known to match. Others are reported by
+// `check_version`, since a mismatch silently corrupts memory rather than failing to link.
+const SUPPORTED_VERSIONS: std::ops::Range<(u32, u32)> = (5, 0)..(6, 0);
+
+// Static libraries that assimp builds alongside itself and links into a shared library, but
+// that have to be linked separately with a static one. Keyed by the first version with that
+// layout: 5.0 builds IrrXML as its own library, 5.1 replaced it with the header-only pugixml.
+const AUXILIARY_LIBS: &[((u32, u32), &[&str])] = &[((5, 0), &["IrrXML"]), ((5, 1), &[])];
+
+// Where `discover_library` got assimp from.
+enum Backend {
+    Bundled,
+    AssimpDir(std::path::PathBuf),
+    PkgConfig,
+    Homebrew(std::path::PathBuf),
+    Vcpkg,
+}
+
+impl Backend {
+    // The name `build_info::BACKEND` reports.
+    fn name(&self) -> &'static str {
+        match self {
+            Backend::Bundled => "bundled",
+            Backend::AssimpDir(_) => "assimp-dir",
+            Backend::PkgConfig => "pkg-config",
+            Backend::Homebrew(_) => "homebrew",
+            Backend::Vcpkg => "vcpkg",
+        }
+    }
+}
+
+// The system libraries a static assimp and its dependencies use on Windows (the registry,
+// `SHGetFolderPath`, COM for some importers). Which ones depends on the importers, so these are
+// all of them, linking one that isn't needed costs nothing.
+const WINDOWS_SYSTEM_LIBS: &[&str] = &["advapi32", "shell32", "ole32"];
+
+// The environment the bundled build depends on, by name prefix, which makes up the key of its
+// `ASSIMP_SYS_CACHE_DIR` entry along with the sources' commit. Generous on purpose: a variable
+// that doesn't matter only costs a rebuild, a missing one reuses a build that doesn't match.
+const CACHE_KEY_VARS: &[&str] = &[
+    "CARGO_FEATURE_",
+    "CARGO_CFG_",
+    "CARGO_ENCODED_RUSTFLAGS",
+    "TARGET",
+    "HOST",
+    "PROFILE",
+    "OPT_LEVEL",
+    "DEBUG",
+    "CC",
+    "CXX",
+    "CFLAGS",
+    "CXXFLAGS",
+    "AR",
+    "CMAKE",
+    "CRATE_CC_NO_DEFAULTS",
+    "CROSS_",
+    "ASSIMP_SYS_",
+    "DEP_Z_",
+    "PKG_CONFIG",
+    "SDKROOT",
+    "MACOSX_DEPLOYMENT_TARGET",
+    "IPHONEOS_DEPLOYMENT_TARGET",
+    "ANDROID_NDK",
+];
+
+// Variables matching `CACHE_KEY_VARS` that don't change what's built.
+const CACHE_KEY_IGNORED: &[&str] = &[
+    "ASSIMP_SYS_CACHE_DIR",
+    "ASSIMP_SYS_BUILD_TIMING",
+    "ASSIMP_SYS_COMPILER_LAUNCHER",
+    "CMAKE_BUILD_PARALLEL_LEVEL",
+];
+
+// Part of the cache key, bumped whenever `compile_bundled` configures the build differently so
+// that the builds of older versions of the crate aren't reused.
+const CACHE_KEY_VERSION: u32 = 1;
+
+// The C++ standard assimp needs, keyed by the first version needing it. 5.3 started using
+// C++17 features.
+const CXX_STANDARDS: &[((u32, u32), &str)] = &[((5, 0), "11"), ((5, 3), "17")];
+
+// What the build script found or built, and needs to pass on to the bindings and dependents.
+struct Library {
+    include_paths: Vec<std::path::PathBuf>,
+    // The directory the linked assimp is in, if it isn't one the linker searches anyway.
+    lib_dir: Option<std::path::PathBuf>,
+    version: Option<String>,
+}
+
+// Items from assimp's headers that don't follow the `ai`/`AI_` naming but are still needed.
+#[cfg(feature = "buildtime-bindgen")]
+const EXTRA_ALLOWED_VARS: &[&str] = &[
+    // `version.h` compile flags, as returned by `aiGetCompileFlags`
+    "ASSIMP_CFLAGS_.*",
+    // Buffer sizes of `aiString` and `aiTexture::achFormatHint`
+    "MAXLEN",
+    "HINTMAXTEXTURELEN",
+];
+
+// C++ types that are only generated as opaque blobs of the right size and alignment. `wrapper.h`
+// is parsed as C, where the headers hide all of their C++, but with `-x c++` in
+// `ASSIMP_SYS_CLANG_ARGS` the members of the C structs pull in the standard library and assimp's
+// internals, which bindgen can't model faithfully and which change between versions.
+#[cfg(feature = "buildtime-bindgen")]
+const OPAQUE_TYPES: &[&str] = &["std::.*", "Assimp::.*"];
+
+// Enums that are generated as Rust enums. Only enums that Rust hands to assimp belong here:
+// reading a value assimp wrote as a Rust enum is undefined behaviour if it isn't one of the
+// variants, which a newer assimp than the headers can easily do. Bitmasks like
+// `aiPostProcessSteps`, `aiPrimitiveType` or `aiTextureFlags` stay as constants.
+#[cfg(feature = "buildtime-bindgen")]
+const RUSTIFIED_ENUMS: &[&str] = &["aiTextureType"];
+
+// Enums that assimp writes, in struct fields, out-parameters, return values or the arguments of
+// callbacks like `aiFileSeek`. They're generated as newtypes over the integer with the variants
+// as associated constants, which can be matched on like variants but also hold any other value.
+#[cfg(feature = "buildtime-bindgen")]
+const NEWTYPE_ENUMS: &[&str] = &[
+    "aiAnimBehaviour",
+    "aiBlendMode",
+    "aiLightSourceType",
+    "aiMetadataType",
+    "aiOrigin",
+    "aiPropertyTypeInfo",
+    "aiReturn",
+    "aiShadingMode",
+    "aiTextureMapMode",
+    "aiTextureMapping",
+    "aiTextureOp",
+];
+
+// Plain value types that get a `Default` impl, which is all zeros like `mem::zeroed`. Structs
+// holding pointers or counts of what they point to are left out, since an empty one isn't
+// something assimp ever hands out and would only be a null pointer waiting to be followed.
+#[cfg(feature = "buildtime-bindgen")]
+const DEFAULT_TYPES: &[&str] = &[
+    "aiColor3D",
+    "aiColor4D",
+    "aiMatrix3x3",
+    "aiMatrix4x4",
+    "aiPlane",
+    "aiQuatKey",
+    "aiQuaternion",
+    "aiRay",
+    "aiTexel",
+    "aiUVTransform",
+    "aiVector2D",
+    "aiVector3D",
+    "aiVectorKey",
+];
+
+// Submodules that re-export the bindings grouped by topic, along with their docs and the
+// prefixes of the items they hold. An item goes to the module with its longest matching prefix,
+// so `aiTextureType` ends up in `material` rather than `texture`. Everything stays available
+// from the crate root as well.
+const MODULES: &[(&str, &str, &[&str])] = &[
+    (
+        "anim",
+        "Node and mesh animations, and their keyframes.",
+        &[
+            "aiAnimation",
+            "aiAnimBehaviour",
+            "aiNodeAnim",
+            "aiMeshAnim",
+            "aiMeshMorphAnim",
+            "aiVectorKey",
+            "aiQuatKey",
+            "aiMeshKey",
+            "aiMeshMorphKey",
+        ],
+    ),
+    (
+        "material",
+        "Materials, their properties and the texture settings stored in them.",
+        &[
+            "aiMaterial",
+            "aiGetMaterial",
+            "aiPropertyTypeInfo",
+            "aiShadingMode",
+            "aiBlendMode",
+            "aiUVTransform",
+            "aiTextureType",
+            "aiTextureOp",
+            "aiTextureMapMode",
+            "aiTextureMapping",
+            "aiTextureFlags",
+            "AI_DEFAULT_MATERIAL_NAME",
+        ],
+    ),
+    (
+        "mesh",
+        "Meshes, faces, bones and morph targets.",
+        &[
+            "aiMesh",
+            "aiFace",
+            "aiBone",
+            "aiVertexWeight",
+            "aiAnimMesh",
+            "aiPrimitiveType",
+            "aiMorphingMethod",
+            "aiAABB",
+            "AI_MAX_",
+        ],
+    ),
+    (
+        "postprocess",
+        "Post-processing steps applied during or after import.",
+        &["aiPostProcessSteps", "aiProcess", "aiApplyPostProcessing"],
+    ),
+    (
+        "scene",
+        "Importing, the scene graph, cameras, lights and metadata.",
+        &[
+            "aiScene",
+            "aiNode",
+            "aiMetadata",
+            "aiCamera",
+            "aiLight",
+            "aiImport",
+            "aiReleaseImport",
+            "AI_SCENE_FLAGS",
+        ],
+    ),
+    (
+        "texture",
+        "Embedded textures.",
+        &["aiTexture", "aiTexel", "HINTMAXTEXTURELEN"],
+    ),
+];
 
 fn main() {
-    let out_path = std::path::PathBuf::from(env::var_os("OUT_DIR").unwrap());
-    let manifest_dir = std::path::PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    // Variables the assimp-sys specific knobs are read from are tracked where they're used, but
+    // these come from cargo and are read here, by cmake-rs or by cc. Cargo already reruns the
+    // script for most changes to them, listing them makes sure none can leave a stale build.
+    for var in CARGO_ENV {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    let out_path = std::path::PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is not set"));
+    let manifest_dir = std::path::PathBuf::from(
+        env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set"),
+    );
+
+    // docs.rs can't build assimp within its limits, and rustdoc doesn't need anything to be
+    // linked, so only generate the bindings from the bundled headers there.
+    println!("cargo:rerun-if-env-changed=DOCS_RS");
+    if env::var_os("DOCS_RS").is_some() {
+        let library = bundled_headers(&out_path, &manifest_dir);
+        write_build_info(&out_path, "docs.rs", library.version.as_deref());
+        generate_bindings(&out_path, &manifest_dir, library.include_paths);
+        write_modules(&out_path);
+        return;
+    }
 
-    let include_paths = match pkg_config::Config::new().exactly_version("5.0").probe("assimp") {
-        Ok(assimp) => {
-            for path in assimp.link_paths {
-                println!("cargo:rustc-link-path={}", path.to_str().unwrap());
+    // With `dynamic-loading` assimp is opened at runtime, so only headers are needed.
+    let (library, backend) = if cfg!(feature = "dynamic-loading") {
+        (find_headers(&out_path, &manifest_dir), None)
+    } else {
+        let (library, backend) = timed("discover_library", || discover_library(&out_path, &manifest_dir));
+        (library, Some(backend))
+    };
+
+    // Surfaced to the build scripts of dependents as `DEP_ASSIMP_VERSION`, `DEP_ASSIMP_LIB_DIR`,
+    // `DEP_ASSIMP_INCLUDE` and `DEP_ASSIMP_PRECISION`.
+    if let Some(version) = &library.version {
+        println!("cargo:version={}", version);
+    }
+    if let Some(lib_dir) = &library.lib_dir {
+        println!("cargo:lib_dir={}", path_to_str(lib_dir));
+    }
+    let include = env::join_paths(&library.include_paths)
+        .expect("An include path contains the platform's path separator");
+    println!("cargo:include={}", include.to_string_lossy());
+
+    // A `rustc-cfg` only applies to this crate, so dependents get the precision through the
+    // metadata and can turn it into a cfg of their own.
+    let double_precision = cfg!(feature = "double-precision");
+    println!("cargo:precision={}", if double_precision { "double" } else { "single" });
+    println!("cargo:rustc-check-cfg=cfg(assimp_double_precision)");
+    if double_precision {
+        println!("cargo:rustc-cfg=assimp_double_precision");
+    }
+
+    let backend_name = backend.as_ref().map_or("dynamic-loading", Backend::name);
+    write_build_info(&out_path, backend_name, library.version.as_deref());
+
+    timed("generate_bindings", || generate_bindings(&out_path, &manifest_dir, library.include_paths));
+    write_modules(&out_path);
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+// Runs `f`, and with `ASSIMP_SYS_BUILD_TIMING` set reports how long it took as a warning, to
+// see where the build time goes.
+fn timed<T>(phase: &str, f: impl FnOnce() -> T) -> T {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_BUILD_TIMING");
+    if env::var_os("ASSIMP_SYS_BUILD_TIMING").is_none() {
+        return f();
+    }
+
+    let start = std::time::Instant::now();
+    let result = f();
+    println!("cargo:warning={} took {:.1}s", phase, start.elapsed().as_secs_f64());
+    result
+}
+
+fn discover_library(out_path: &Path, manifest_dir: &Path) -> (Library, Backend) {
+    if cfg!(all(feature = "vendored", feature = "system")) {
+        panic!("The `vendored` and `system` features of assimp-sys are mutually exclusive");
+    }
+
+    if cfg!(all(feature = "system-zlib", feature = "zlib-ng")) {
+        panic!("The `system-zlib` and `zlib-ng` features of assimp-sys are mutually exclusive");
+    }
+
+    println!("cargo:rerun-if-env-changed=ASSIMP_DIR");
+
+    let (library, backend) = if cfg!(feature = "vendored") {
+        (compile_bundled(out_path, manifest_dir), Backend::Bundled)
+    } else if let Some(assimp_dir) = env::var_os("ASSIMP_DIR") {
+        (link_assimp_dir(Path::new(&assimp_dir)), Backend::AssimpDir(assimp_dir.into()))
+    } else {
+        match probe_pkg_config() {
+            Ok(library) => (library, Backend::PkgConfig),
+            Err(err) => {
+                // vcpkg-rs prints the link lines of what it finds, so it's only asked once
+                // Homebrew came up empty.
+                if let Some(prefix) = homebrew_prefix("assimp") {
+                    (link_assimp_dir(&prefix), Backend::Homebrew(prefix))
+                } else if let Some(library) = probe_vcpkg() {
+                    (library, Backend::Vcpkg)
+                } else {
+                    if cfg!(feature = "system") {
+                        panic!(
+                            "The `system` feature is enabled but no compatible system assimp could \
+                             be found via pkg-config{}: {}",
+                            if cfg!(feature = "vcpkg") { " or vcpkg" } else { "" },
+                            err
+                        );
+                    }
+
+                    (compile_bundled(out_path, manifest_dir), Backend::Bundled)
+                }
             }
-            for lib in assimp.libs {
+        }
+    };
+
+    // Compiling assimp takes minutes, so make it visible which way was taken.
+    let version = library.version.as_deref().map(|version| format!(" {}", version)).unwrap_or_default();
+    let summary = match &backend {
+        Backend::Bundled => format!(
+            "compiling the bundled assimp{} ({}; {})",
+            version,
+            format_summary("importer", IMPORTERS, false),
+            format_summary("exporter", EXPORTERS, cfg!(feature = "no-exporters"))
+        ),
+        Backend::AssimpDir(path) => format!("using assimp{} from `ASSIMP_DIR` ({})", version, path.display()),
+        Backend::PkgConfig => format!("using the system assimp{} via pkg-config", version),
+        Backend::Homebrew(prefix) => format!("using the Homebrew assimp{} ({})", version, prefix.display()),
+        Backend::Vcpkg => format!("using the vcpkg assimp{}", version),
+    };
+    println!("cargo:warning=assimp-sys: {}", summary);
+
+    check_version(library.version.as_deref());
+
+    // Only needed when assimp was built against a system minizip rather than its own
+    // `contrib/unzip`, and harmless to skip when it can't be found.
+    if cfg!(feature = "system-minizip") {
+        if let Ok(minizip) = pkg_config::probe_library("minizip") {
+            for path in minizip.link_paths {
+                println!("cargo:rustc-link-search=native={}", path_to_str(&path));
+            }
+            for lib in minizip.libs {
                 println!("cargo:rustc-link-lib={}", lib);
             }
+        }
+    }
+
+    compile_layout_shim(&library.include_paths);
 
-            assimp
-                .include_paths
-                .into_iter()
-                .map(|p| p.into_os_string().into_string().unwrap())
-                .collect::<Vec<_>>()
-        }
-        _ => {
-            // Compile assimp from source
-            // Disable unnecessary stuff, it takes long enough to compile already
-            let dst = Config::new("assimp")
-                .profile("Release")
-                .define("ASSIMP_BUILD_ASSIMP_TOOLS", "OFF")
-                .define("ASSIMP_BUILD_TESTS", "OFF")
-                .define("ASSIMP_INSTALL_PDB", "OFF")
-                .define("BUILD_SHARED_LIBS", "OFF")
-                .define("LIBRARY_SUFFIX", "")
-                .define("CMAKE_SUPPRESS_DEVELOPER_WARNINGS", "ON")
-                // GCC doesn't work here, Assimp explicitly sets `-Werror` but
-                // GCC emits some warnings that clang doesn't, setting `-Wno-error`
-                // doesn't work because Assimp's cmake script adds `-Werror` _after_
-                // our CFLAGS (even with `CMAKE_SUPPRESS_DEVELOPER_WARNINGS=ON`).
-                //
-                // When will C/C++ devs stop setting `-Werror` without a way to disable
-                // it.
-                .define("CMAKE_C_COMPILER", "clang")
-                // For some reason, using `.pic(true)` doesn't work here, only
-                // specifically setting it in CFLAGS
-                .define("CMAKE_C_FLAGS", "-fPIC")
-                .uses_cxx11()
-                .build();
-
-            let dst = dst.join("lib");
-            println!("cargo:rustc-link-search=native={}", dst.display());
-
-            // There's no way to extract this from `cmake::Config` so we have to emulate their
-            // behaviour here (see the source for `cmake::Config::build`).
-            // let debug_postfix = match (
-            //     &env::var("OPT_LEVEL").unwrap_or_default()[..],
-            //     &env::var("PROFILE").unwrap_or_default()[..],
-            // ) {
-            //     ("1", _) | ("2", _) | ("3", _) | ("s", _) | ("z", _) => "",
-            //     ("0", _) => "d",
-            //     (_, "debug") => "d",
-            //     (_, _) => "",
-            // };
-            let debug_postfix = "";
-
-            println!("cargo:rustc-link-lib=static=assimp{}", debug_postfix);
-
-            vec![
-                manifest_dir.join("assimp").join("include").into_os_string().into_string().unwrap(),
-                out_path.join("include").into_os_string().into_string().unwrap(),
-            ]
-        }
-    };
-
-    if let Ok(minizip) = pkg_config::probe_library("minizip") {
-        for path in minizip.link_paths {
-            println!("cargo:rustc-link-path={}", path.to_str().unwrap());
-        }
-        for lib in minizip.libs {
+    if cfg!(feature = "helpers") {
+        compile_shims(&library.include_paths, library.version.as_deref());
+    }
+
+    if let Some(stdlib) = cxx_stdlib_link() {
+        link_cxx_stdlib(&stdlib);
+    }
+
+    if env::var("CARGO_CFG_TARGET_OS").unwrap_or_default() == "windows" {
+        for lib in WINDOWS_SYSTEM_LIBS {
             println!("cargo:rustc-link-lib={}", lib);
         }
     }
 
-    // Link to libstdc++ on GNU
-    let target = env::var("TARGET").unwrap();
-    if target.contains("gnu") {
-        println!("cargo:rustc-link-lib=stdc++");
-    } else if target.contains("apple") {
-        println!("cargo:rustc-link-lib=c++");
+    (library, backend)
+}
+
+// Warns about a library outside of `SUPPORTED_VERSIONS` (or one whose version is unknown), and
+// fails the build instead with `strict-version`.
+fn check_version(version: Option<&str>) {
+    let supported = format!(
+        "{}.{} up to (excluding) {}.{}",
+        SUPPORTED_VERSIONS.start.0, SUPPORTED_VERSIONS.start.1, SUPPORTED_VERSIONS.end.0, SUPPORTED_VERSIONS.end.1
+    );
+
+    let problem = match version {
+        None => format!("Couldn't determine the version of the linked assimp, supported are {}", supported),
+        Some(version) => {
+            match major_minor(version) {
+                Some(major_minor) if SUPPORTED_VERSIONS.contains(&major_minor) => return,
+                _ => format!(
+                    "The linked assimp is version {}, but the bindings only match {}. Using it is \
+                     likely to corrupt memory",
+                    version, supported
+                ),
+            }
+        }
+    };
+
+    if cfg!(feature = "strict-version") {
+        panic!("{} (`strict-version` is enabled)", problem);
+    }
+    println!("cargo:warning={}", problem);
+}
+
+// The major and minor version from a `major[.minor[.patch]]` version string.
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut components = version.split('.').map(|component| component.parse::<u32>().ok());
+    match (components.next(), components.next()) {
+        (Some(Some(major)), Some(Some(minor))) => Some((major, minor)),
+        (Some(Some(major)), None) => Some((major, 0)),
+        _ => None,
+    }
+}
+
+// The C++ standard assimp is compiled with (and the shims against its headers), the one the
+// first version in `CXX_STANDARDS` at or below `version` needs. `ASSIMP_SYS_CXX_STANDARD`
+// overrides it, e.g. for a newer Draco.
+fn cxx_standard(version: Option<&str>) -> &'static str {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_CXX_STANDARD");
+
+    if let Ok(standard) = env::var("ASSIMP_SYS_CXX_STANDARD") {
+        return match &standard[..] {
+            "11" => "11",
+            "14" => "14",
+            "17" => "17",
+            "20" => "20",
+            _ => panic!("`ASSIMP_SYS_CXX_STANDARD` is `{}`, expected one of 11, 14, 17 or 20", standard),
+        };
     }
 
-    println!("cargo:rerun-if-changed=wrapper.h");
+    // Without a known version, the newest requirement is the one that's sure to work.
+    match version.and_then(major_minor) {
+        Some(version) => CXX_STANDARDS
+            .iter()
+            .rev()
+            .find(|(since, _)| *since <= version)
+            .map_or(CXX_STANDARDS[0].1, |(_, standard)| standard),
+        None => CXX_STANDARDS[CXX_STANDARDS.len() - 1].1,
+    }
+}
+
+// Links the static libraries a static assimp of `version` needs besides itself (see
+// `AUXILIARY_LIBS`), if they're in one of `lib_dirs` and not among `linked` already. A shared
+// assimp has them built in, so they just won't be found next to it. Without a known version
+// whichever of them is found is linked.
+fn link_auxiliary_libs(version: Option<&str>, lib_dirs: &[&Path], linked: &[String]) {
+    let expected = match version.and_then(major_minor) {
+        Some(version) => AUXILIARY_LIBS
+            .iter()
+            .rev()
+            .find(|(since, _)| *since <= version)
+            .map(|(_, libs)| libs.to_vec())
+            .unwrap_or_default(),
+        None => AUXILIARY_LIBS.iter().flat_map(|(_, libs)| libs.iter().copied()).collect(),
+    };
+
+    for name in expected {
+        if linked.iter().any(|lib| lib.starts_with(name)) {
+            continue;
+        }
+
+        // Also matches the debug postfix, as in `IrrXMLd`.
+        let found = lib_dirs
+            .iter()
+            .filter(|dir| dir.is_dir())
+            .find_map(|dir| static_libs(dir).into_iter().find(|stem| stem.starts_with(name)));
+        if let Some(lib) = found {
+            println!("cargo:rustc-link-lib=static={}", lib);
+        }
+    }
+}
+
+// `src/shim/layout.c`, which reports the struct sizes of the headers for `tests/layout.rs`. Tiny
+// and plain C, so it's always built.
+fn compile_layout_shim(include_paths: &[std::path::PathBuf]) {
+    let shim = "src/shim/layout.c";
+    println!("cargo:rerun-if-changed={}", shim);
+
+    cc::Build::new()
+        .file(shim)
+        .static_crt(cfg!(feature = "static-crt"))
+        .includes(include_paths)
+        .compile("assimp_sys_layout");
+}
+
+// The C++ wrappers in `src/shim` for what assimp's C API doesn't expose, used by `helpers`.
+fn compile_shims(include_paths: &[std::path::PathBuf], version: Option<&str>) {
+    let shims = ["src/shim/progress.cpp"];
+
+    let mut build = cc::Build::new();
+    build
+        .cpp(true)
+        // Linked by `discover_library` instead, which knows more targets than cc does.
+        .cpp_link_stdlib(None)
+        .flag_if_supported(format!("-std=c++{}", cxx_standard(version)).as_str())
+        .static_crt(cfg!(feature = "static-crt"))
+        .includes(include_paths);
+
+    for shim in &shims {
+        println!("cargo:rerun-if-changed={}", shim);
+        build.file(shim);
+    }
+
+    build.compile("assimp_sys_shims");
+}
+
+// Headers of a system assimp if pkg-config knows one, otherwise the bundled ones, without
+// emitting any link directives.
+fn find_headers(out_path: &Path, manifest_dir: &Path) -> Library {
+    let probe = pkg_config_assimp().cargo_metadata(false).probe("assimp");
+
+    if let Ok(assimp) = probe {
+        return Library {
+            include_paths: assimp.include_paths,
+            lib_dir: None,
+            version: Some(assimp.version),
+        };
+    }
+
+    bundled_headers(out_path, manifest_dir)
+}
+
+// The bundled headers, along with the `config.h` a CMake build would have generated.
+fn bundled_headers(out_path: &Path, manifest_dir: &Path) -> Library {
+    // `config.h` is normally generated by CMake from `config.h.in`, the only option in it
+    // that matters to the C API is `ASSIMP_DOUBLE_PRECISION`.
+    let config_in_path = manifest_dir.join("assimp").join("include").join("assimp").join("config.h.in");
+    let config_in = std::fs::read_to_string(&config_in_path)
+        .unwrap_or_else(|err| panic!("Couldn't read `{}`: {}", config_in_path.display(), err));
+    let config = config_in
+        .lines()
+        .map(|line| match line.trim_start().strip_prefix("#cmakedefine") {
+            Some(rest) => {
+                let name = rest.split_whitespace().next().unwrap_or_default();
+                if name == "ASSIMP_DOUBLE_PRECISION" && cfg!(feature = "double-precision") {
+                    format!("#define{}", rest)
+                } else {
+                    format!("/* #undef {} */", name)
+                }
+            }
+            None => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let generated_include = out_path.join("include");
+    let config_dir = generated_include.join("assimp");
+    std::fs::create_dir_all(&config_dir)
+        .unwrap_or_else(|err| panic!("Couldn't create `{}`: {}", config_dir.display(), err));
+    std::fs::write(config_dir.join("config.h"), config)
+        .unwrap_or_else(|err| panic!("Couldn't write `{}`: {}", config_dir.display(), err));
+
+    Library {
+        include_paths: vec![manifest_dir.join("assimp").join("include"), generated_include],
+        lib_dir: None,
+        version: bundled_version(manifest_dir),
+    }
+}
+
+// The C++ standard library assimp needs, `ASSIMP_SYS_CXX_STDLIB` overrides it (an empty value
+// links none).
+fn cxx_stdlib_link() -> Option<String> {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_CXX_STDLIB");
+    println!("cargo:rerun-if-env-changed=CXXFLAGS");
+
+    if let Ok(stdlib) = env::var("ASSIMP_SYS_CXX_STDLIB") {
+        return if stdlib.is_empty() { None } else { Some(stdlib) };
+    }
+
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    let uses_libcxx = env::var("CXXFLAGS").unwrap_or_default().contains("-stdlib=libc++");
+
+    let stdlib = match (&target_os[..], &target_env[..]) {
+        // MSVC links its C++ runtime on its own.
+        (_, "msvc") => return None,
+        ("macos", _) | ("ios", _) | ("freebsd", _) | ("openbsd", _) => "c++",
+        // The NDK's default, and the only C++ library that can be shared between several
+        // native libraries in one app.
+        ("android", _) => "c++_shared",
+        _ if uses_libcxx => "c++",
+        _ => "stdc++",
+    };
+
+    Some(stdlib.to_owned())
+}
+
+// Links `stdlib` statically or dynamically, see `static_cxx_stdlib`. rustc looks for a static
+// library itself rather than leaving it to the linker, so it has to be told where the compiler
+// keeps it.
+fn link_cxx_stdlib(stdlib: &str) {
+    if !static_cxx_stdlib() {
+        println!("cargo:rustc-link-lib={}", stdlib);
+        return;
+    }
+
+    // The NDK's static variant has a different name.
+    let stdlib = if stdlib == "c++_shared" { "c++_static" } else { stdlib };
+    if let Some(dir) = compiler_lib_dir(&format!("lib{}.a", stdlib)) {
+        println!("cargo:rustc-link-search=native={}", dir.display());
+    }
+    println!("cargo:rustc-link-lib=static={}", stdlib);
+}
+
+// Whether the C++ standard library is linked statically. `ASSIMP_SYS_STATIC_STDCXX` decides if
+// it's set (`0` or empty for dynamic), otherwise it's static on musl only, where the point is
+// usually a binary without any shared library dependencies.
+fn static_cxx_stdlib() -> bool {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_STATIC_STDCXX");
+    match env::var("ASSIMP_SYS_STATIC_STDCXX") {
+        Ok(value) => !value.is_empty() && value != "0",
+        Err(_) => env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default() == "musl",
+    }
+}
 
-    // Tell cargo we really want to rebuild if the main sources changed.
-    for dirent in walkdir::WalkDir::new("assimp").min_depth(1) {
-        let dirent = dirent.unwrap();
-        let filename = dirent.file_name();
-        let filename = filename.to_str().unwrap();
-        if filename.ends_with(".h") || filename.ends_with(".cpp") || filename.ends_with(".inl") {
+// The directory the target's C++ compiler finds `file` in, asked with `-print-file-name`. That
+// prints the name unchanged if it can't find it.
+fn compiler_lib_dir(file: &str) -> Option<std::path::PathBuf> {
+    let compiler = cc::Build::new().cpp(true).cargo_metadata(false).try_get_compiler().ok()?;
+    let output = compiler.to_command().arg(format!("-print-file-name={}", file)).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let path = std::path::PathBuf::from(String::from_utf8(output.stdout).ok()?.trim());
+    if path.is_absolute() {
+        path.parent().map(Path::to_path_buf)
+    } else {
+        None
+    }
+}
+
+// The pkg-config query for the assimp versions that are accepted, also used for the headers
+// with `dynamic-loading`. The C API is compatible across 5.x, `ASSIMP_SYS_VERSION` can pin an
+// exact version.
+fn pkg_config_assimp() -> pkg_config::Config {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_VERSION");
+
+    let mut config = pkg_config::Config::new();
+    match env::var("ASSIMP_SYS_VERSION") {
+        Ok(version) => config.exactly_version(&version),
+        Err(_) => config.range_version("5.0".."6.0"),
+    };
+    config
+}
 
-            println!("cargo:rerun-if-changed={}", dirent.path().to_str().unwrap());
+fn probe_pkg_config() -> Result<Library, pkg_config::Error> {
+    let mut config = pkg_config_assimp();
+
+    // pkg-config only links static libraries outside of the system directories statically, so
+    // with a kind forced all of the link lines come from here instead.
+    let kind = assimp_link_kind();
+    if let Some(kind) = kind {
+        config.statik(kind == "static").cargo_metadata(false);
+    }
+
+    let assimp = config.probe("assimp")?;
+
+    for path in &assimp.link_paths {
+        println!("cargo:rustc-link-search=native={}", path_to_str(path));
+    }
+    for lib in &assimp.libs {
+        match kind {
+            Some(kind) if lib.starts_with("assimp") => println!("cargo:rustc-link-lib={}={}", kind, lib),
+            _ => println!("cargo:rustc-link-lib={}", lib),
         }
+    }
+    let link_paths = assimp.link_paths.iter().map(|path| path.as_path()).collect::<Vec<_>>();
+    link_auxiliary_libs(Some(&assimp.version), &link_paths, &assimp.libs);
+
+    Ok(Library {
+        // pkg-config leaves out the directories the linker searches by default.
+        lib_dir: assimp.link_paths.first().cloned(),
+        include_paths: assimp.include_paths,
+        version: Some(assimp.version),
+    })
+}
+
+// Where Homebrew installed `formula`, when building natively on macOS with `brew` on the
+// `PATH`. Homebrew's `.pc` files aren't on the default `PKG_CONFIG_PATH` under `/opt/homebrew`,
+// so pkg-config misses its assimp on Apple Silicon.
+fn homebrew_prefix(formula: &str) -> Option<std::path::PathBuf> {
+    let target = env::var("TARGET").ok()?;
+    if !target.ends_with("-apple-darwin") || env::var("HOST").ok()? != target {
+        return None;
+    }
+
+    let brew = which::which("brew").ok()?;
+    let output = std::process::Command::new(brew).args(["--prefix", formula]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // `brew --prefix <formula>` prints the prefix whether or not the formula is installed.
+    let prefix = std::path::PathBuf::from(String::from_utf8(output.stdout).ok()?.trim());
+    if prefix.join("include").is_dir() && prefix.join("lib").is_dir() {
+        Some(prefix)
+    } else {
+        None
+    }
+}
+
+// An assimp installed with `vcpkg install assimp`, the usual way to get system libraries on
+// Windows where pkg-config is rare. vcpkg-rs emits the link lines for it and its dependencies.
+#[cfg(feature = "vcpkg")]
+fn probe_vcpkg() -> Option<Library> {
+    let assimp = vcpkg::find_package("assimp").ok()?;
+    Some(Library {
+        version: assimp.include_paths.iter().find_map(|dir| revision_header_version(dir)),
+        lib_dir: assimp.link_paths.first().cloned(),
+        include_paths: assimp.include_paths,
+    })
+}
+
+#[cfg(not(feature = "vcpkg"))]
+fn probe_vcpkg() -> Option<Library> {
+    None
+}
+
+// The kind a system assimp is linked as, from `ASSIMP_SYS_LINK`. Without it the linker picks
+// (usually the shared library when both are installed).
+fn assimp_link_kind() -> Option<&'static str> {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_LINK");
+    match env::var("ASSIMP_SYS_LINK").as_deref() {
+        Ok("static") => Some("static"),
+        Ok("dylib") => Some("dylib"),
+        Ok("") | Err(_) => None,
+        Ok(other) => panic!("Unknown `ASSIMP_SYS_LINK` value `{}`, expected `static` or `dylib`", other),
+    }
+}
+
+// An assimp installed to `ASSIMP_DIR`, laid out as `lib/` and `include/` without a `.pc` file.
+fn link_assimp_dir(assimp_dir: &Path) -> Library {
+    println!("cargo:rustc-link-search=native={}", assimp_dir.join("lib").display());
+    match assimp_link_kind() {
+        Some(kind) => println!("cargo:rustc-link-lib={}=assimp", kind),
+        None => println!("cargo:rustc-link-lib=assimp"),
+    }
+
+    let include_dir = assimp_dir.join("include");
+    let version = revision_header_version(&include_dir);
+    link_auxiliary_libs(version.as_deref(), &[&assimp_dir.join("lib")], &["assimp".to_owned()]);
+
+    Library {
+        version,
+        include_paths: vec![include_dir],
+        lib_dir: Some(assimp_dir.join("lib")),
+    }
+}
+
+// Installed assimp headers record the version in the generated `assimp/revision.h`.
+fn revision_header_version(include_dir: &Path) -> Option<String> {
+    let header = std::fs::read_to_string(include_dir.join("assimp").join("revision.h")).ok()?;
+
+    let component = |name: &str| {
+        header.lines().find_map(|line| {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some("#define"), Some(define), Some(value)) if define == name => {
+                    Some(value.to_owned())
+                }
+                _ => None,
+            }
+        })
     };
 
+    Some(format!("{}.{}.{}", component("VER_MAJOR")?, component("VER_MINOR")?, component("VER_PATCH")?))
+}
+
+// The bundled assimp declares its version in its top-level `CMakeLists.txt`, either as
+// `SET(ASSIMP_VERSION_MAJOR 5)` and friends or, in newer versions, `PROJECT(Assimp VERSION 5.2.0)`.
+fn bundled_version(manifest_dir: &Path) -> Option<String> {
+    let cmake_lists =
+        std::fs::read_to_string(manifest_dir.join("assimp").join("CMakeLists.txt")).ok()?;
+
+    let args = cmake_lists.lines().filter_map(|line| {
+        let line = line.trim();
+        let start = line.find('(')?;
+        let end = line.rfind(')')?;
+        let command = line[..start].trim().to_lowercase();
+        let args = line.get(start + 1..end)?.split_whitespace().collect::<Vec<_>>();
+        Some((command, args))
+    });
+
+    let mut components = [None, None, None];
+    for (command, args) in args {
+        match (&command[..], &args[..]) {
+            ("project", [name, "VERSION", version, ..]) if name.eq_ignore_ascii_case("assimp") => {
+                return Some(version.to_string());
+            }
+            ("set", [name, value]) => {
+                let index = match *name {
+                    "ASSIMP_VERSION_MAJOR" => 0,
+                    "ASSIMP_VERSION_MINOR" => 1,
+                    "ASSIMP_VERSION_PATCH" => 2,
+                    _ => continue,
+                };
+                components[index] = Some(value.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    match components {
+        [Some(major), Some(minor), Some(patch)] => Some(format!("{}.{}.{}", major, minor, patch)),
+        _ => None,
+    }
+}
+
+fn compile_bundled(out_path: &Path, manifest_dir: &Path) -> Library {
+    // A clone without `--recursive` leaves the submodule empty, which cmake only reports as a
+    // missing source directory.
+    if !manifest_dir.join("assimp").join("CMakeLists.txt").is_file() {
+        panic!(
+            "The bundled assimp sources are missing from `{}`. If this is a git checkout, run \
+             `git submodule update --init --recursive` to fetch them.",
+            manifest_dir.join("assimp").display()
+        );
+    }
+
+    let is_msvc = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default() == "msvc";
+    let version = bundled_version(manifest_dir);
+
+    // Compile assimp from source
+    // Disable unnecessary stuff, it takes long enough to compile already
+    let mut config = Config::new("assimp");
+    config
+        .profile(&bundled_build_type())
+        .define("ASSIMP_BUILD_ASSIMP_TOOLS", if cfg!(feature = "tools") { "ON" } else { "OFF" })
+        .define("ASSIMP_BUILD_TESTS", "OFF")
+        .define("ASSIMP_INSTALL_PDB", "OFF")
+        .define("BUILD_SHARED_LIBS", if cfg!(feature = "shared") { "ON" } else { "OFF" })
+        .define("LIBRARY_SUFFIX", "")
+        .define("CMAKE_SUPPRESS_DEVELOPER_WARNINGS", "ON");
+
+    // As flags rather than `CMAKE_CXX_STANDARD`, which assimp's own `CMakeLists.txt` may set.
+    // MSVC can't go below C++14, its default.
+    let cxx_standard = cxx_standard(version.as_deref());
+    if !is_msvc {
+        config.cxxflag(format!("-std=c++{}", cxx_standard));
+    } else if cxx_standard != "11" && cxx_standard != "14" {
+        config.cxxflag(format!("/std:c++{}", cxx_standard));
+    }
+
+    for (key, value) in importer_defines() {
+        config.define(key, value);
+    }
+
+    for (key, value) in exporter_defines() {
+        config.define(key, value);
+    }
+
+    if cfg!(feature = "double-precision") {
+        config.define("ASSIMP_DOUBLE_PRECISION", "ON");
+    }
+
+    if cfg!(feature = "draco") {
+        config.define("ASSIMP_BUILD_DRACO", "ON");
+    }
+
+    if cfg!(feature = "openmp") {
+        config.define("ASSIMP_OPENMP", "ON");
+    }
+
+    if cfg!(feature = "system-zlib") {
+        configure_system_zlib(&mut config);
+    }
+
+    if cfg!(feature = "zlib-ng") {
+        configure_zlib_ng(&mut config);
+    }
+
+    if is_msvc && cfg!(feature = "static-crt") {
+        // `CMAKE_MSVC_RUNTIME_LIBRARY` is ignored unless policy CMP0091 is enabled, which
+        // assimp's `cmake_minimum_required` doesn't do for us. cmake-rs also needs to be told,
+        // otherwise the flags it gets from `cc` still contain `/MD`.
+        config
+            .static_crt(true)
+            .define("CMAKE_POLICY_DEFAULT_CMP0091", "NEW")
+            .define("CMAKE_MSVC_RUNTIME_LIBRARY", "MultiThreaded$<$<CONFIG:Debug>:Debug>");
+    }
+
+    if !is_msvc {
+        // For some reason, using `.pic(true)` doesn't work here, only
+        // specifically setting it in CFLAGS. Use `cflag` so that it's appended
+        // to whatever flags cmake-rs and the user's `CFLAGS` already provide.
+        config.cflag("-fPIC");
+
+        configure_compiler(&mut config);
+    }
+
+    // Through CMake rather than `-flto` in the flags, so it's applied with the right flags for the
+    // compiler and doesn't interfere with the `-fPIC` above. CMP0069 makes CMake honour it at all
+    // for assimp's `cmake_minimum_required`.
+    if cfg!(feature = "lto") {
+        config
+            .define("CMAKE_POLICY_DEFAULT_CMP0069", "NEW")
+            .define("CMAKE_INTERPROCEDURAL_OPTIMIZATION", "ON");
+    }
+
+    configure_float_model(&mut config, is_msvc);
+    configure_sanitizer(&mut config, is_msvc);
+    configure_generator(&mut config, is_msvc);
+    configure_launcher(&mut config);
+
+    // Applied last so that they can override anything set above.
+    for (key, value) in extra_cmake_defines() {
+        config.define(key, value);
+    }
+
+    let dst = match bundled_cache_dir() {
+        Some(cache_dir) => build_cached(&mut config, &cache_dir, out_path, manifest_dir),
+        None => timed("compile_bundled (cmake)", || config.build()),
+    };
+
+    if cfg!(feature = "tools") {
+        stage_tool(out_path, &dst);
+    }
+
+    // A shared assimp already links everything it was built with, only a static one needs its
+    // dependencies listed.
+    let lib_dir = dst.join("lib");
+    if cfg!(feature = "shared") {
+        link_shared_assimp(out_path, &dst);
+    } else {
+        link_static_assimp(&lib_dir, version.as_deref(), is_msvc);
+    }
+
+    track_bundled_sources(manifest_dir);
+
+    Library {
+        include_paths: vec![manifest_dir.join("assimp").join("include"), out_path.join("include")],
+        lib_dir: Some(lib_dir),
+        version,
+    }
+}
+
+// Links the static assimp in `lib_dir` along with the libraries it was built with.
+fn link_static_assimp(lib_dir: &Path, version: Option<&str>, is_msvc: bool) {
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+    // The library names depend on the toolset, build type and assimp version (e.g.
+    // `assimp-vc142-mt.lib` or `libassimpd.a`), so use whatever was actually built.
+    let assimp = find_assimp_lib(lib_dir, static_libs(lib_dir));
+    if cfg!(feature = "strip") && !is_msvc {
+        strip_debug_symbols(&lib_dir.join(format!("lib{}.a", assimp)));
+    }
+    println!("cargo:rustc-link-lib=static={}", assimp);
+    link_auxiliary_libs(version, &[lib_dir], &[assimp]);
+    if is_msvc && !cfg!(any(feature = "system-zlib", feature = "zlib-ng")) {
+        // MSVC builds get assimp's own zlib by default.
+        let zlib = static_libs(lib_dir)
+            .into_iter()
+            .find(|stem| stem.starts_with("zlibstatic"))
+            .unwrap_or_else(|| panic!("No zlib was built in `{}`", lib_dir.display()));
+        println!("cargo:rustc-link-lib=static={}", zlib);
+    }
+
+    // Linked after assimp, which depends on it.
+    if cfg!(feature = "draco") {
+        link_draco(lib_dir);
+    }
+
+    if cfg!(feature = "openmp") {
+        link_openmp(is_msvc);
+    }
+
+    // Checked by `configure_system_zlib` already, libz-sys links its own build itself.
+    if cfg!(feature = "system-zlib") && libz_sys_zlib().is_none() {
+        if let Err(err) = pkg_config::probe_library("zlib") {
+            panic!("The `system-zlib` feature is enabled but zlib wasn't found via pkg-config: {}", err);
+        }
+    }
+}
+
+// Links the shared assimp installed to `dst` and copies its runtime files to `OUT_DIR`, from
+// where dependents can stage them next to their executables through `DEP_ASSIMP_SHARED`. On
+// Windows what's linked is the import library in `lib`, which is named differently from the DLL
+// in `bin` that's loaded at runtime.
+fn link_shared_assimp(out_path: &Path, dst: &Path) {
+    let is_windows = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default() == "windows";
+    let lib_dir = dst.join("lib");
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+    let (linked, runtime_dir) = if is_windows {
+        (static_libs(&lib_dir), dst.join("bin"))
+    } else {
+        (shared_libs(&lib_dir), lib_dir.clone())
+    };
+    println!("cargo:rustc-link-lib=dylib={}", find_assimp_lib(&lib_dir, linked));
+
+    // Cargo adds the search paths below `target` to `PATH` for `cargo run` and `cargo test`,
+    // which is the only place Windows looks for the DLL.
+    if is_windows {
+        println!("cargo:rustc-link-search=native={}", runtime_dir.display());
+    }
+
+    let runtime = runtime_files(&runtime_dir);
+    if runtime.is_empty() {
+        panic!("No shared assimp was built in `{}`", runtime_dir.display());
+    }
+
+    let staged = runtime
+        .iter()
+        .map(|file| {
+            // `copy` follows symlinks, so each of the names is a complete library.
+            let staged = out_path.join(file.file_name().unwrap());
+            std::fs::copy(file, &staged)
+                .unwrap_or_else(|err| panic!("Couldn't copy `{}`: {}", file.display(), err));
+            staged
+        })
+        .collect::<Vec<_>>();
+    let staged = env::join_paths(&staged).expect("`OUT_DIR` contains the platform's path separator");
+    println!("cargo:shared={}", staged.to_string_lossy());
+}
+
+// Copies the `assimp` command line tool installed to `dst` to `OUT_DIR`, and passes its path on
+// to dependents as `DEP_ASSIMP_TOOL`. It's called `assimpd` in debug builds.
+fn stage_tool(out_path: &Path, dst: &Path) {
+    let bin_dir = dst.join("bin");
+    let is_windows = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default() == "windows";
+    let names: &[&str] = if is_windows { &["assimp.exe", "assimpd.exe"] } else { &["assimp", "assimpd"] };
+
+    let tool = names
+        .iter()
+        .map(|name| bin_dir.join(name))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| panic!("The `tools` feature is enabled but no assimp tool was built in `{}`", bin_dir.display()));
+
+    let staged = out_path.join(tool.file_name().unwrap());
+    std::fs::copy(&tool, &staged).unwrap_or_else(|err| panic!("Couldn't copy `{}`: {}", tool.display(), err));
+    println!("cargo:tool={}", staged.display());
+}
+
+// Every name the shared assimp is installed under in `dir`, e.g. `libassimp.so.5` next to
+// `libassimp.so`, as the loader looks for the soname rather than the name it was linked with.
+fn runtime_files(dir: &Path) -> Vec<std::path::PathBuf> {
+    let entries =
+        std::fs::read_dir(dir).unwrap_or_else(|err| panic!("Couldn't read `{}`: {}", dir.display(), err));
+
+    let mut files = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let is_assimp = name.starts_with("assimp") || name.starts_with("libassimp");
+            let is_shared = name.ends_with(".dll")
+                || name.ends_with(".dylib")
+                || name.ends_with(".so")
+                || name.contains(".so.");
+            is_assimp && is_shared
+        })
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+// Makes assimp use an existing zlib instead of building `contrib/zlib`, which it quietly falls
+// back to (and ends up linking a second zlib) when CMake doesn't find one. So it's checked here
+// and CMake is pointed at the zlib that's going to be linked: the one libz-sys built if it
+// built one (for example for flate2's default backend), otherwise the system one.
+fn configure_system_zlib(config: &mut Config) {
+    config.define("ASSIMP_BUILD_ZLIB", "OFF");
+
+    if let Some((root, include)) = libz_sys_zlib() {
+        config.define("ZLIB_ROOT", root).define("ZLIB_INCLUDE_DIR", include);
+        return;
+    }
+
+    match pkg_config::Config::new().cargo_metadata(false).probe("zlib") {
+        Ok(zlib) => {
+            if let Some(include) = zlib.include_paths.first() {
+                config.define("ZLIB_INCLUDE_DIR", include);
+            }
+        }
+        Err(err) => panic!("The `system-zlib` feature is enabled but zlib wasn't found via pkg-config: {}", err),
+    }
+}
+
+// Builds assimp against the zlib-ng libz-sys compiled in zlib compatible mode. libz-sys links
+// it, so only one set of zlib symbols ends up in the binary.
+fn configure_zlib_ng(config: &mut Config) {
+    let (root, include) = libz_sys_zlib().unwrap_or_else(|| {
+        panic!(
+            "The `zlib-ng` feature is enabled but libz-sys didn't build zlib-ng, check that \
+             nothing else enables its `stock-zlib` feature"
+        )
+    });
+
+    config
+        .define("ASSIMP_BUILD_ZLIB", "OFF")
+        .define("ZLIB_ROOT", root)
+        .define("ZLIB_INCLUDE_DIR", include);
+}
+
+// The install prefix and include directory of the zlib libz-sys built, from the `DEP_Z_*`
+// metadata of its build script. `DEP_Z_ROOT` is only set when it built one rather than using the
+// system's.
+fn libz_sys_zlib() -> Option<(String, String)> {
+    println!("cargo:rerun-if-env-changed=DEP_Z_ROOT");
+    println!("cargo:rerun-if-env-changed=DEP_Z_INCLUDE");
+    match (env::var("DEP_Z_ROOT"), env::var("DEP_Z_INCLUDE")) {
+        (Ok(root), Ok(include)) => Some((root, include)),
+        _ => None,
+    }
+}
+
+// Sets the floating point model explicitly instead of leaving it to the toolchain's defaults
+// (and whatever `CFLAGS` a distro sets), so that imports give the same values everywhere. The
+// `fast-math` feature trades that for speed.
+fn configure_float_model(config: &mut Config, is_msvc: bool) {
+    let flag = match (is_msvc, cfg!(feature = "fast-math")) {
+        (false, false) => "-fno-fast-math",
+        (false, true) => "-ffast-math",
+        (true, false) => "/fp:precise",
+        (true, true) => "/fp:fast",
+    };
+    config.cflag(flag).cxxflag(flag);
+}
+
+// Instruments the bundled build with the sanitizer named by `ASSIMP_SYS_SANITIZE`. Only one
+// sanitizer runtime can be in a process: with `-Zsanitizer=address` in the `RUSTFLAGS` rustc links
+// its own (LLVM's, which needs assimp to be compiled by a matching clang), otherwise the C++
+// compiler's is. MSVC picks its runtime on its own.
+fn configure_sanitizer(config: &mut Config, is_msvc: bool) {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_SANITIZE");
+    println!("cargo:rerun-if-env-changed=CARGO_ENCODED_RUSTFLAGS");
+    match env::var("ASSIMP_SYS_SANITIZE").as_deref() {
+        Ok("address") => {}
+        Ok("") | Err(_) => return,
+        Ok(other) => panic!("Unsupported `ASSIMP_SYS_SANITIZE` value `{}`, only `address` is", other),
+    }
+
+    if is_msvc {
+        config.cflag("/fsanitize=address").cxxflag("/fsanitize=address");
+        return;
+    }
+
+    // Without frame pointers the reports' stack traces stop at assimp's frames.
+    for flag in &["-fsanitize=address", "-fno-omit-frame-pointer"] {
+        config.cflag(flag).cxxflag(flag);
+    }
+
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    if !rustflags.split('\x1f').any(|flag| flag.ends_with("sanitizer=address")) {
+        println!("cargo:rustc-link-lib=asan");
+    }
+}
+
+// Removes the debug symbols from a static archive, with `STRIP` or whichever of `llvm-strip` and
+// `strip` is found. MSVC keeps them in separate PDB files, so there's nothing to do there.
+fn strip_debug_symbols(archive: &Path) {
+    println!("cargo:rerun-if-env-changed=STRIP");
+
+    let strip = env::var_os("STRIP")
+        .map(std::path::PathBuf::from)
+        .or_else(|| which::which("llvm-strip").ok())
+        .or_else(|| which::which("strip").ok())
+        .unwrap_or_else(|| panic!("The `strip` feature is enabled but no `strip` was found, set `STRIP`"));
+
+    let status = std::process::Command::new(&strip)
+        .arg("--strip-debug")
+        .arg(archive)
+        .status()
+        .unwrap_or_else(|err| panic!("Couldn't run `{}`: {}", strip.display(), err));
+    if !status.success() {
+        panic!("`{}` failed to strip `{}`: {}", strip.display(), archive.display(), status);
+    }
+}
+
+// `ASSIMP_SYS_CACHE_DIR`, if set. The cache isn't used with `ASSIMP_SYS_TRACK_SOURCES`, as
+// uncommitted edits to the sources aren't part of its key.
+fn bundled_cache_dir() -> Option<std::path::PathBuf> {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_CACHE_DIR");
+    let dir = env::var_os("ASSIMP_SYS_CACHE_DIR").filter(|dir| !dir.is_empty())?;
+    if env::var_os("ASSIMP_SYS_TRACK_SOURCES").is_some() {
+        return None;
+    }
+
+    Some(dir.into())
+}
+
+// Builds and installs assimp into an entry of `cache_dir`, unless there's one for this
+// configuration already, and copies the installed files to `out_path` where an uncached build
+// would have put them. Returns `out_path`, the equivalent of `Config::build`'s result.
+fn build_cached(
+    config: &mut Config,
+    cache_dir: &Path,
+    out_path: &Path,
+    manifest_dir: &Path,
+) -> std::path::PathBuf {
+    use std::fs;
+
+    let key = bundled_cache_key(manifest_dir);
+    let entry = cache_dir.join(&key);
+    if entry.is_dir() {
+        println!("cargo:warning=assimp-sys: reusing the cached build `{}`", entry.display());
+    } else {
+        // Built under another name and renamed once complete, so that a failed or concurrent
+        // build never leaves a partial entry behind.
+        let partial = cache_dir.join(format!("{}.{}", key, std::process::id()));
+        let _ = fs::remove_dir_all(&partial);
+        config.out_dir(&partial);
+        timed("compile_bundled (cmake)", || config.build());
+
+        // Only the installed files are reused, not the objects.
+        let _ = fs::remove_dir_all(partial.join("build"));
+        match fs::rename(&partial, &entry) {
+            Ok(()) => {}
+            // Another build of the same configuration finished first.
+            Err(_) if entry.is_dir() => {
+                let _ = fs::remove_dir_all(&partial);
+            }
+            Err(err) => panic!("Couldn't move the build to `{}`: {}", entry.display(), err),
+        }
+    }
+
+    // Copied rather than linked to, so that cleaning the cache doesn't break this build. The
+    // files behind a shared library's version symlinks are copied under each name.
+    for dirent in walkdir::WalkDir::new(&entry).min_depth(1) {
+        let dirent = dirent.unwrap_or_else(|err| panic!("Couldn't walk `{}`: {}", entry.display(), err));
+        let dst = out_path.join(dirent.path().strip_prefix(&entry).unwrap());
+        let result = if dirent.file_type().is_dir() {
+            fs::create_dir_all(&dst)
+        } else {
+            fs::copy(dirent.path(), &dst).map(|_| ())
+        };
+        result.unwrap_or_else(|err| panic!("Couldn't copy `{}`: {}", dirent.path().display(), err));
+    }
+
+    out_path.to_owned()
+}
+
+// The name of the bundled build's cache entry: a hash of the sources' commit, the environment in
+// `CACHE_KEY_VARS` and the C++ compiler's version. A new Rust toolchain may hash differently,
+// which only costs a rebuild.
+fn bundled_cache_key(manifest_dir: &Path) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    CACHE_KEY_VERSION.hash(&mut hasher);
+
+    // As in `track_bundled_sources`, the sources of a packaged crate only change with its version.
+    match checked_out_commit(&manifest_dir.join("assimp")) {
+        Some(commit) => commit.hash(&mut hasher),
+        None => env!("CARGO_PKG_VERSION").hash(&mut hasher),
+    }
+
+    let mut vars = env::vars_os()
+        .filter(|(name, _)| {
+            let name = name.to_string_lossy();
+            let matches = CACHE_KEY_VARS.iter().any(|prefix| name.starts_with(prefix));
+            matches && !CACHE_KEY_IGNORED.contains(&&*name)
+        })
+        .collect::<Vec<_>>();
+    vars.sort();
+    vars.hash(&mut hasher);
+
+    // The C++ compiler `cc` resolves, which cmake-rs passes on to CMake when one was picked, and
+    // the clang that `configure_compiler` picks by default. Their paths stay the same when
+    // they're upgraded, so it's their versions that count.
+    let compiler = cc::Build::new().cpp(true).cargo_metadata(false).get_compiler();
+    for mut command in [compiler.to_command(), std::process::Command::new("clang")] {
+        if let Ok(output) = command.arg("--version").output() {
+            output.stdout.hash(&mut hasher);
+            output.stderr.hash(&mut hasher);
+        }
+    }
+
+    format!("{:016x}", hasher.finish())
+}
+
+// The commit checked out at `path`, if it's a git checkout.
+fn checked_out_commit(path: &Path) -> Option<String> {
+    let git_dir = submodule_git_dir(path)?;
+    let head = std::fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let reference = match head.trim().strip_prefix("ref: ") {
+        Some(reference) => reference,
+        None => return Some(head.trim().to_owned()),
+    };
+
+    if let Ok(commit) = std::fs::read_to_string(git_dir.join(reference)) {
+        return Some(commit.trim().to_owned());
+    }
+
+    // Refs that git has packed no longer have a file of their own.
+    let packed = std::fs::read_to_string(git_dir.join("packed-refs")).ok()?;
+    packed
+        .lines()
+        .find_map(|line| line.strip_suffix(reference)?.strip_suffix(' '))
+        .map(str::to_owned)
+}
+
+// Reruns the build when the bundled sources change. Emitting every source file would make cargo
+// stat thousands of files on each build, so only the top-level `CMakeLists.txt` and the
+// submodule's `HEAD` (which moves whenever the submodule is updated) are tracked. Setting
+// `ASSIMP_SYS_TRACK_SOURCES` tracks every source file instead, for working on assimp itself.
+fn track_bundled_sources(manifest_dir: &Path) {
+    let assimp_dir = manifest_dir.join("assimp");
+    println!("cargo:rerun-if-changed={}", path_to_str(&assimp_dir.join("CMakeLists.txt")));
+
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_TRACK_SOURCES");
+    if env::var_os("ASSIMP_SYS_TRACK_SOURCES").is_some() {
+        for dirent in walkdir::WalkDir::new(&assimp_dir).min_depth(1) {
+            let dirent = dirent.unwrap_or_else(|err| panic!("Couldn't walk the assimp sources: {}", err));
+            let filename = dirent.file_name().to_string_lossy();
+            if filename.ends_with(".h") || filename.ends_with(".cpp") || filename.ends_with(".inl") {
+                println!("cargo:rerun-if-changed={}", path_to_str(dirent.path()));
+            }
+        }
+        return;
+    }
+
+    // Packaged crates aren't git checkouts, but there the sources only change along with the
+    // crate version anyway.
+    let git_dir = match submodule_git_dir(&assimp_dir) {
+        Some(git_dir) => git_dir,
+        None => return,
+    };
+
+    let head = git_dir.join("HEAD");
+    println!("cargo:rerun-if-changed={}", path_to_str(&head));
+
+    // On a branch rather than a detached commit it's the branch's ref that moves.
+    if let Ok(contents) = std::fs::read_to_string(&head) {
+        if let Some(reference) = contents.trim().strip_prefix("ref: ") {
+            println!("cargo:rerun-if-changed={}", path_to_str(&git_dir.join(reference)));
+        }
+    }
+}
+
+// The git directory of the checkout at `path`. For a submodule that's usually
+// `.git/modules/<name>` of the superproject, pointed to by a `.git` file.
+fn submodule_git_dir(path: &Path) -> Option<std::path::PathBuf> {
+    let dot_git = path.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+
+    let contents = std::fs::read_to_string(&dot_git).ok()?;
+    let git_dir = contents.trim().strip_prefix("gitdir: ")?;
+    Some(path.join(git_dir))
+}
+
+// The OpenMP runtime of the compiler the bundled assimp was built with: GCC's `gomp`, or LLVM's
+// `omp` for clang (which on macOS comes from Homebrew's `libomp`). MSVC links its `vcomp` on
+// its own. `ASSIMP_SYS_OPENMP_LIB` overrides it, an empty value links none.
+fn link_openmp(is_msvc: bool) {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_OPENMP_LIB");
+
+    let lib = match env::var("ASSIMP_SYS_OPENMP_LIB") {
+        Ok(lib) => lib,
+        Err(_) if is_msvc => return,
+        Err(_) => {
+            // `configure_compiler` defaults to clang and clang++ unless a compiler was picked.
+            let uses_gcc = ["CMAKE_CXX_COMPILER", "CXX"]
+                .iter()
+                .find_map(|var| env::var(var).ok())
+                .is_some_and(|compiler| compiler.contains("g++") || compiler.contains("gcc"));
+            if uses_gcc { "gomp" } else { "omp" }.to_owned()
+        }
+    };
+
+    if lib.is_empty() {
+        return;
+    }
+
+    if let Some(prefix) = homebrew_prefix("libomp") {
+        println!("cargo:rustc-link-search=native={}", path_to_str(&prefix.join("lib")));
+    }
+    println!("cargo:rustc-link-lib={}", lib);
+}
+
+// Depending on the version Draco builds a single `draco` archive or separate `dracodec` and
+// `dracoenc` ones, so link whatever actually ended up in `lib_dir`.
+fn link_draco(lib_dir: &Path) {
+    let archives = static_libs(lib_dir)
+        .into_iter()
+        .filter(|stem| stem.starts_with("draco"))
+        .collect::<Vec<_>>();
+
+    if archives.is_empty() {
+        panic!("The `draco` feature is enabled but no Draco library was built in `{}`", lib_dir.display());
+    }
+
+    for archive in archives {
+        println!("cargo:rustc-link-lib=static={}", archive);
+    }
+}
+
+// The `rustc-link-lib` name of the assimp library built in `lib_dir`, out of the `libs` found
+// there.
+fn find_assimp_lib(lib_dir: &Path, libs: Vec<String>) -> String {
+    let mut assimp = libs
+        .into_iter()
+        .filter(|stem| stem.starts_with("assimp"));
+
+    match (assimp.next(), assimp.next()) {
+        (Some(stem), None) => stem,
+        (None, _) => panic!("No assimp library was built in `{}`", lib_dir.display()),
+        (Some(first), Some(second)) => panic!(
+            "Found several assimp libraries in `{}` (`{}`, `{}`, ...), clean the build directory",
+            lib_dir.display(),
+            first,
+            second
+        ),
+    }
+}
+
+// Names of the static libraries in `lib_dir`, without the `lib` prefix and extension. Sorted so
+// that the link order is stable.
+fn static_libs(lib_dir: &Path) -> Vec<String> {
+    let entries = std::fs::read_dir(lib_dir)
+        .unwrap_or_else(|err| panic!("Couldn't read `{}`: {}", lib_dir.display(), err));
+
+    let mut stems = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| {
+            let stem = name
+                .strip_suffix(".a")
+                .and_then(|stem| stem.strip_prefix("lib"))
+                .or_else(|| name.strip_suffix(".lib"))?;
+            Some(stem.to_owned())
+        })
+        .collect::<Vec<_>>();
+    stems.sort();
+    stems
+}
+
+// Names of the shared libraries in `lib_dir` as they're linked, so without the versioned names
+// (`libassimp.so.5`, `libassimp.5.dylib`) they're also installed under.
+fn shared_libs(lib_dir: &Path) -> Vec<String> {
+    let entries = std::fs::read_dir(lib_dir)
+        .unwrap_or_else(|err| panic!("Couldn't read `{}`: {}", lib_dir.display(), err));
+
+    let mut stems = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| {
+            let stem = name
+                .strip_suffix(".so")
+                .or_else(|| name.strip_suffix(".dylib"))?
+                .strip_prefix("lib")?;
+            Some(stem.to_owned()).filter(|stem| !stem.contains('.'))
+        })
+        .collect::<Vec<_>>();
+    stems.sort();
+    stems
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    println!("cargo:rerun-if-env-changed={}", var);
+    env::var_os(var).is_some()
+}
+
+// With no `<kind>-*` feature enabled every format in `formats` is built, otherwise only the
+// selected ones are.
+fn format_defines(kind: &str, formats: &[(&str, &'static str)]) -> Vec<(&'static str, &'static str)> {
+    let selected = formats
+        .iter()
+        .map(|&(name, option)| (option, feature_enabled(&format!("{}-{}", kind, name))))
+        .collect::<Vec<_>>();
+
+    if !selected.iter().any(|&(_, enabled)| enabled) {
+        return Vec::new();
+    }
+
+    selected
+        .into_iter()
+        .map(|(option, enabled)| (option, if enabled { "ON" } else { "OFF" }))
+        .collect()
+}
+
+// Which `<kind>-*` formats the bundled assimp gets, as in "all importers" or
+// "exporters obj, gltf2".
+fn format_summary(kind: &str, formats: &[(&str, &str)], disabled: bool) -> String {
+    let selected = formats
+        .iter()
+        .filter(|(name, _)| feature_enabled(&format!("{}-{}", kind, name)))
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+
+    if disabled || (selected.is_empty() && cfg!(feature = "minimal")) {
+        format!("no {}s", kind)
+    } else if selected.is_empty() {
+        format!("all {}s", kind)
+    } else {
+        format!("{}s {}", kind, selected.join(", "))
+    }
+}
+
+// The `minimal` feature starts from an assimp with no formats at all, so that only the ones
+// selected through features get built (which may be none).
+fn minimal_define(option: &'static str) -> Vec<(&'static str, &'static str)> {
+    if cfg!(feature = "minimal") {
+        vec![(option, "OFF")]
+    } else {
+        Vec::new()
+    }
+}
+
+fn importer_defines() -> Vec<(&'static str, &'static str)> {
+    let mut defines = minimal_define("ASSIMP_BUILD_ALL_IMPORTERS_BY_DEFAULT");
+    defines.extend(format_defines("importer", IMPORTERS));
+    defines
+}
+
+fn exporter_defines() -> Vec<(&'static str, &'static str)> {
+    if cfg!(feature = "no-exporters") {
+        return vec![("ASSIMP_NO_EXPORT", "ON")];
+    }
+
+    let mut defines = minimal_define("ASSIMP_BUILD_ALL_EXPORTERS_BY_DEFAULT");
+    defines.extend(format_defines("exporter", EXPORTERS));
+    defines
+}
+
+// cmake-rs already passes cargo's `NUM_JOBS` on as `--parallel`, but Makefile builds of
+// assimp still scale badly, so prefer Ninja when it's available. On MSVC the Visual Studio
+// generator is kept since Ninja would need a developer prompt environment.
+fn configure_generator(config: &mut Config, is_msvc: bool) {
+    println!("cargo:rerun-if-env-changed=CMAKE_GENERATOR");
+
+    if is_msvc || env::var_os("CMAKE_GENERATOR").is_some() {
+        return;
+    }
+
+    if which::which("ninja").is_ok() {
+        config.generator("Ninja");
+    }
+}
+
+// Runs the compiler through `ASSIMP_SYS_COMPILER_LAUNCHER`, or `sccache` or `ccache` if either
+// is on the `PATH`. An empty `ASSIMP_SYS_COMPILER_LAUNCHER` disables this, and so do CMake's own
+// `CMAKE_<LANG>_COMPILER_LAUNCHER` variables, which CMake reads itself.
+fn configure_launcher(config: &mut Config) {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_COMPILER_LAUNCHER");
+    println!("cargo:rerun-if-env-changed=CMAKE_C_COMPILER_LAUNCHER");
+    println!("cargo:rerun-if-env-changed=CMAKE_CXX_COMPILER_LAUNCHER");
+
+    if env::var_os("CMAKE_C_COMPILER_LAUNCHER").is_some() || env::var_os("CMAKE_CXX_COMPILER_LAUNCHER").is_some() {
+        return;
+    }
+
+    let launcher = match env::var_os("ASSIMP_SYS_COMPILER_LAUNCHER") {
+        Some(launcher) if launcher.is_empty() => return,
+        Some(launcher) => std::path::PathBuf::from(launcher),
+        None => match which::which("sccache").or_else(|_| which::which("ccache")) {
+            Ok(launcher) => launcher,
+            Err(_) => return,
+        },
+    };
+
+    config
+        .define("CMAKE_C_COMPILER_LAUNCHER", &launcher)
+        .define("CMAKE_CXX_COMPILER_LAUNCHER", &launcher);
+}
+
+// Escape hatch for assimp options we don't expose, as `KEY1=VAL1;KEY2=VAL2`.
+fn extra_cmake_defines() -> Vec<(String, String)> {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_CMAKE_DEFINES");
+
+    let defines = match env::var("ASSIMP_SYS_CMAKE_DEFINES") {
+        Ok(defines) => defines,
+        Err(_) => return Vec::new(),
+    };
+
+    defines
+        .split(';')
+        .map(str::trim)
+        .filter(|define| !define.is_empty())
+        .map(|define| match define.find('=') {
+            Some(i) => (define[..i].trim().to_owned(), define[i + 1..].trim().to_owned()),
+            None => panic!(
+                "Invalid entry `{}` in ASSIMP_SYS_CMAKE_DEFINES, expected `KEY=VALUE`",
+                define
+            ),
+        })
+        .collect()
+}
+
+// The CMake build type of the bundled assimp. It's `Release` regardless of the cargo profile,
+// as a debug assimp is very slow to import anything, unless `ASSIMP_SYS_BUILD_TYPE` asks for
+// another one (e.g. `Debug` to debug assimp itself).
+fn bundled_build_type() -> String {
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_BUILD_TYPE");
+
+    match env::var("ASSIMP_SYS_BUILD_TYPE") {
+        Ok(build_type) => match &build_type[..] {
+            "Debug" | "Release" | "RelWithDebInfo" | "MinSizeRel" => build_type,
+            _ => panic!(
+                "`ASSIMP_SYS_BUILD_TYPE` is `{}`, expected one of `Debug`, `Release`, \
+                 `RelWithDebInfo` or `MinSizeRel`",
+                build_type
+            ),
+        },
+        Err(_) => "Release".to_owned(),
+    }
+}
+
+fn configure_compiler(config: &mut Config) {
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rerun-if-env-changed=CXX");
+    println!("cargo:rerun-if-env-changed=CMAKE_C_COMPILER");
+    println!("cargo:rerun-if-env-changed=CMAKE_CXX_COMPILER");
+
+    if let Some(cc) = env::var_os("CMAKE_C_COMPILER") {
+        config.define("CMAKE_C_COMPILER", cc);
+    }
+    if let Some(cxx) = env::var_os("CMAKE_CXX_COMPILER") {
+        config.define("CMAKE_CXX_COMPILER", cxx);
+    }
+
+    let user_compiler = ["CC", "CXX", "CMAKE_C_COMPILER", "CMAKE_CXX_COMPILER"]
+        .iter()
+        .any(|var| env::var_os(var).is_some());
+
+    // If the user picked a compiler, cmake-rs resolves it the same way `cc::Build` does and
+    // we leave it alone. Otherwise default to clang:
+    //
+    // GCC doesn't work here, Assimp explicitly sets `-Werror` but
+    // GCC emits some warnings that clang doesn't, setting `-Wno-error`
+    // doesn't work because Assimp's cmake script adds `-Werror` _after_
+    // our CFLAGS (even with `CMAKE_SUPPRESS_DEVELOPER_WARNINGS=ON`).
+    //
+    // When will C/C++ devs stop setting `-Werror` without a way to disable
+    // it.
+    if !user_compiler {
+        config.define("CMAKE_C_COMPILER", "clang");
+        config.define("CMAKE_CXX_COMPILER", "clang++");
+    }
+}
+
+// Paths are kept as `Path`s and only turned into strings where one is required: in the
+// instructions to cargo and the arguments to libclang, neither of which can represent anything
+// but UTF-8. Non-ASCII paths are fine, only ones that aren't valid Unicode at all can't work.
+fn path_to_str(path: &Path) -> &str {
+    path.to_str().unwrap_or_else(|| {
+        panic!("Path `{}` is not valid UTF-8, which cargo and libclang need", path.display())
+    })
+}
+
+// The sysroot of the target when cross-compiling, either from `CROSS_SYSROOT` or asked from
+// the C compiler cc would use for the target (which works for GCC cross toolchains).
+#[cfg(feature = "buildtime-bindgen")]
+fn cross_sysroot() -> Option<String> {
+    println!("cargo:rerun-if-env-changed=CROSS_SYSROOT");
+    if let Ok(sysroot) = env::var("CROSS_SYSROOT") {
+        return Some(sysroot);
+    }
+
+    let target = env::var("TARGET").ok()?;
+    let host = env::var("HOST").ok()?;
+    if target == host || target.contains("msvc") {
+        return None;
+    }
+
+    let compiler = cc::Build::new().cargo_metadata(false).try_get_compiler().ok()?;
+    let output = compiler.to_command().arg("-print-sysroot").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let sysroot = String::from_utf8(output.stdout).ok()?.trim().to_owned();
+    if sysroot.is_empty() {
+        None
+    } else {
+        Some(sysroot)
+    }
+}
+
+#[cfg(feature = "buildtime-bindgen")]
+#[derive(Debug)]
+struct Callbacks;
+
+#[cfg(feature = "buildtime-bindgen")]
+impl bindgen::callbacks::ParseCallbacks for Callbacks {
+    fn include_file(&self, filename: &str) {
+        bindgen::CargoCallbacks.include_file(filename)
+    }
+
+    // `aiString` is blocklisted so it can have a `Debug` impl that only prints the valid bytes,
+    // and `ai_real` so it can be documented. The hand-written items implement everything the
+    // generated ones would, so stop bindgen from dropping the derives of every struct
+    // containing one.
+    fn blocklisted_type_implements_trait(
+        &self,
+        name: &str,
+        _derive_trait: bindgen::callbacks::DeriveTrait,
+    ) -> Option<bindgen::callbacks::ImplementsTrait> {
+        if name == "aiString" || name == "ai_real" {
+            Some(bindgen::callbacks::ImplementsTrait::Yes)
+        } else {
+            None
+        }
+    }
+
+    // bindgen's own `derive_default` applies to every struct, see `DEFAULT_TYPES` for why only
+    // some get one.
+    fn add_derives(&self, name: &str) -> Vec<String> {
+        if DEFAULT_TYPES.contains(&name) {
+            vec!["Default".to_owned()]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "buildtime-bindgen")]
+fn generate_bindings(out_path: &Path, manifest_dir: &Path, include_paths: Vec<std::path::PathBuf>) {
+    println!("cargo:rerun-if-changed=wrapper.h");
+
     let mut bindings = bindgen::Builder::default()
         .header("wrapper.h")
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks))
-        .blacklist_item("FP_ZERO")
-        .blacklist_item("FP_SUBNORMAL")
-        .blacklist_item("FP_NORMAL")
-        .blacklist_item("FP_NAN")
-        .blacklist_item("FP_INFINITE")
+        .parse_callbacks(Box::new(Callbacks))
+        // Defined by hand in `src/lib.rs`, see `Callbacks`.
+        .blocklist_type("aiString")
+        .blocklist_type("ai_real")
+        // Only generate assimp's own symbols, instead of everything libc and libm put in scope.
+        // Types used by these are pulled in automatically.
+        .allowlist_function("ai.*")
+        .allowlist_type("ai.*")
+        .allowlist_var("AI_.*|ai.*")
+        // No `Eq`/`Hash`: most of assimp's value types (`aiVector3D`, `aiMatrix4x4`, `aiColor4D`,
+        // ...) are made of floats, and the rest contain them or raw pointers, so deriving those
+        // would only ever apply to a random subset of types with surprising semantics.
         .derive_partialeq(true)
-        .derive_eq(true)
-        .derive_hash(true)
-        .derive_debug(true);
+        .derive_debug(true)
+        // `size_t` has the same width as `usize` on every target assimp supports, which the
+        // generated layout tests check.
+        .size_t_is_usize(true)
+        // The default, but relied on: they make `cargo test` fail when the bindings' layouts
+        // don't match what libclang computed from the headers, e.g. for `ai_real`, which is
+        // defined by the `double-precision` feature rather than the headers' `config.h`.
+        .layout_tests(true)
+        // Carries assimp's Doxygen comments over as doc comments. Without
+        // `-fparse-all-comments` libclang only keeps the ones in `///` and `/**` form, which
+        // misses a good part of the headers. See `clean_doxygen` for the markup.
+        .generate_comments(true)
+        .clang_arg("-fparse-all-comments");
+
+    for var in EXTRA_ALLOWED_VARS {
+        bindings = bindings.allowlist_var(var);
+    }
 
-    for path in include_paths {
-        bindings = bindings.clang_args(&["-I", &path]);
+    for name in RUSTIFIED_ENUMS {
+        bindings = bindings.rustified_enum(name);
+    }
+
+    for name in NEWTYPE_ENUMS {
+        bindings = bindings.newtype_enum(name);
+    }
+
+    for pattern in OPAQUE_TYPES {
+        bindings = bindings.opaque_type(pattern);
+    }
+
+    // Passed to libclang as separate arguments rather than through a shell, so spaces in the
+    // paths need no quoting. libclang takes them as C strings, hence the UTF-8 requirement.
+    for path in &include_paths {
+        bindings = bindings.clang_args(&["-I", path_to_str(path)]);
+    }
+
+    // Generates `struct Assimp` holding every function, loaded with libloading, instead of
+    // `extern` declarations.
+    if cfg!(feature = "dynamic-loading") {
+        bindings = bindings.dynamic_library_name("Assimp");
+    }
+
+    // This has to agree with how the linked library was compiled, as it changes `ai_real` and
+    // with it the layout of every vector and matrix struct.
+    if cfg!(feature = "double-precision") {
+        bindings = bindings.clang_arg("-DASSIMP_DOUBLE_PRECISION");
+    }
+
+    // `core::ffi` has the C types since Rust 1.64, so the bindings don't need std at all.
+    if cfg!(feature = "no-std") {
+        bindings = bindings.use_core().ctypes_prefix("::core::ffi");
+    }
+
+    // Extra flags for libclang, for headers in unusual places or cross-compiling.
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_CLANG_ARGS");
+    let extra_args = env::var("ASSIMP_SYS_CLANG_ARGS").unwrap_or_default();
+
+    // bindgen already passes `--target` based on `TARGET` when cross-compiling, but libclang
+    // will still use the host's headers unless it's told where the target's sysroot is.
+    if !extra_args.contains("--sysroot") {
+        if let Some(sysroot) = cross_sysroot() {
+            bindings = bindings.clang_arg(format!("--sysroot={}", sysroot));
+        }
     }
 
+    bindings = bindings.clang_args(split_args(&extra_args));
+
     let bindings = bindings.generate().expect("Unable to generate bindings");
+    let bindings = clean_doxygen(&bindings.to_string());
 
     let bindings_path = out_path.join("bindings.rs");
-    bindings.write_to_file(&bindings_path).expect("Couldn't write bindings");
+    std::fs::write(&bindings_path, &bindings).expect("Couldn't write bindings");
 
-    println!("cargo:rerun-if-changed=build.rs");
+    // Used to refresh `src/bindings_pregenerated.rs`, this should be done with the `vendored`
+    // feature so that they match the bundled assimp.
+    println!("cargo:rerun-if-env-changed=ASSIMP_SYS_UPDATE_PREGENERATED");
+    if env::var_os("ASSIMP_SYS_UPDATE_PREGENERATED").is_some() {
+        let pregenerated_path = manifest_dir.join("src").join("bindings_pregenerated.rs");
+        std::fs::write(&pregenerated_path, &bindings)
+            .expect("Couldn't write pregenerated bindings");
+    }
+}
+
+// Splits `args` at whitespace, except inside single or double quotes, so that paths with spaces
+// (`-isystem "C:\Program Files\assimp\include"`) can be passed. Backslashes are kept as they
+// are, as they're the path separator on Windows.
+#[cfg(feature = "buildtime-bindgen")]
+fn split_args(args: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    let mut current = None::<String>;
+    let mut quote = None;
+
+    for c in args.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"') | (None, '\'') => {
+                quote = Some(c);
+                current.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => split.extend(current.take()),
+            (_, c) => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    split.extend(current);
+    split
+}
+
+// bindgen 0.59 has no hook for rewriting comments, so this goes over the generated
+// `#[doc = "..."]` lines instead. The Doxygen commands assimp uses most are either dropped
+// (`@brief`) or turned into something that reads well in rustdoc, anything else is kept as is.
+#[cfg(feature = "buildtime-bindgen")]
+fn clean_doxygen(bindings: &str) -> String {
+    let mut cleaned = String::with_capacity(bindings.len());
+    for line in bindings.lines() {
+        match line.split_once("#[doc = \"") {
+            Some((indent, doc)) if indent.trim().is_empty() => {
+                cleaned.push_str(indent);
+                cleaned.push_str("#[doc = \"");
+                cleaned.push_str(&clean_doxygen_line(doc));
+            }
+            _ => cleaned.push_str(line),
+        }
+        cleaned.push('\n');
+    }
+    cleaned
+}
+
+#[cfg(feature = "buildtime-bindgen")]
+fn clean_doxygen_line(doc: &str) -> String {
+    let text = doc.trim_start();
+    let leading = &doc[..doc.len() - text.len()];
+    // The backslash form is escaped in the string literal.
+    let body = match text.strip_prefix('@').or_else(|| text.strip_prefix("\\\\")) {
+        Some(body) => body,
+        None => return doc.to_owned(),
+    };
+    let command = body
+        .split(|c: char| c.is_whitespace() || c == '"')
+        .next()
+        .unwrap_or("");
+    let rest = body[command.len()..].trim_start();
+
+    match command {
+        "brief" => format!("{}{}", leading, rest),
+        "param" | "param[in]" | "param[out]" | "param[in,out]" => {
+            match rest.split_once(char::is_whitespace) {
+                Some((name, description)) => {
+                    format!("{}* `{}`: {}", leading, name, description.trim_start())
+                }
+                None => format!("{}{}", leading, rest),
+            }
+        }
+        "return" | "returns" => format!("{}Returns: {}", leading, rest),
+        "note" => format!("{}Note: {}", leading, rest),
+        _ => doc.to_owned(),
+    }
+}
+
+// Without `buildtime-bindgen` the checked-in bindings (generated against the bundled assimp)
+// are used instead, so that libclang isn't needed.
+#[cfg(not(feature = "buildtime-bindgen"))]
+fn generate_bindings(out_path: &Path, manifest_dir: &Path, _include_paths: Vec<std::path::PathBuf>) {
+    if cfg!(feature = "dynamic-loading") {
+        panic!("The `dynamic-loading` feature requires the `buildtime-bindgen` feature");
+    }
+
+    if cfg!(feature = "double-precision") {
+        panic!(
+            "The pregenerated bindings use single precision, enable the `buildtime-bindgen` \
+             feature to use `double-precision`"
+        );
+    }
+
+    if cfg!(feature = "no-std") {
+        panic!(
+            "The pregenerated bindings use std's C types, enable the `buildtime-bindgen` \
+             feature to use `no-std`"
+        );
+    }
+
+    use_pregenerated_bindings(out_path, manifest_dir);
+}
+
+#[cfg(not(feature = "buildtime-bindgen"))]
+fn use_pregenerated_bindings(out_path: &Path, manifest_dir: &Path) {
+    use std::fs;
+
+    let pregenerated_path = manifest_dir.join("src").join("bindings_pregenerated.rs");
+    println!("cargo:rerun-if-changed={}", pregenerated_path.display());
+
+    if !pregenerated_path.exists() {
+        panic!(
+            "`{}` is missing, it can be regenerated by building with \
+             `--features vendored,buildtime-bindgen` and `ASSIMP_SYS_UPDATE_PREGENERATED=1`",
+            pregenerated_path.display()
+        );
+    }
+
+    fs::copy(&pregenerated_path, out_path.join("bindings.rs")).unwrap_or_else(|err| {
+        panic!("Couldn't copy `{}`: {}", pregenerated_path.display(), err)
+    });
+}
+
+// Writes `modules.rs` next to `bindings.rs`, with the submodules described by `MODULES`.
+fn write_modules(out_path: &Path) {
+    use std::fs;
+
+    let bindings_path = out_path.join("bindings.rs");
+    let bindings = fs::read_to_string(&bindings_path)
+        .unwrap_or_else(|err| panic!("Couldn't read `{}`: {}", bindings_path.display(), err));
+
+    let mut modules = vec![Vec::new(); MODULES.len()];
+    for item in binding_items(&bindings) {
+        let best = MODULES
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (_, _, prefixes))| prefixes.iter().map(move |prefix| (i, prefix)))
+            .filter(|(_, prefix)| item.starts_with(*prefix))
+            .max_by_key(|(_, prefix)| prefix.len());
+
+        if let Some((i, _)) = best {
+            modules[i].push(item);
+        }
+    }
+
+    let mut source = String::new();
+    for ((name, doc, _), items) in MODULES.iter().zip(modules) {
+        source.push_str(&format!("/// {}\npub mod {} {{\n", doc, name));
+        if !items.is_empty() {
+            source.push_str(&format!("    pub use super::{{{}}};\n", items.join(", ")));
+        }
+        source.push_str("}\n\n");
+    }
+
+    fs::write(out_path.join("modules.rs"), source).expect("Couldn't write modules.rs");
+}
+
+// Writes `build_info.rs`, the constants of the `build_info` module. `backend` is the name of the
+// `Backend` (or how else the bindings came about) and `version` the version of the library.
+fn write_build_info(out_path: &Path, backend: &str, version: Option<&str>) {
+    // Only the bundled build is known to contain just the selected importers.
+    let importers = if backend == Backend::Bundled.name() { bundled_importers() } else { Vec::new() };
+
+    let source = format!(
+        "/// Where assimp came from: `bundled`, `assimp-dir`, `pkg-config`, `homebrew` or\n\
+         /// `vcpkg`, or `dynamic-loading` if it's loaded at runtime and `docs.rs` if nothing was\n\
+         /// linked (the bindings are then generated from the bundled headers).\n\
+         pub const BACKEND: &str = {:?};\n\n\
+         /// The version of assimp the crate was built against, empty if it couldn't be determined.\n\
+         pub const VERSION: &str = {:?};\n\n\
+         /// Whether `ai_real` is `f64`, i.e. the `double-precision` feature is enabled.\n\
+         pub const DOUBLE_PRECISION: bool = {};\n\n\
+         /// The importers built into the bundled assimp, by their `importer-*` feature names. Empty\n\
+         /// unless `BACKEND` is `bundled`, as the build script can't tell for any other library.\n\
+         pub const IMPORTERS: &[&str] = &{:?};\n",
+        backend,
+        version.unwrap_or(""),
+        cfg!(feature = "double-precision"),
+        importers
+    );
+
+    std::fs::write(out_path.join("build_info.rs"), source).expect("Couldn't write build_info.rs");
+}
+
+// The names of the importers the bundled build enables, the same selection as `importer_defines`.
+fn bundled_importers() -> Vec<&'static str> {
+    let selected = IMPORTERS
+        .iter()
+        .filter(|(name, _)| feature_enabled(&format!("importer-{}", name)))
+        .map(|&(name, _)| name)
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() && !cfg!(feature = "minimal") {
+        IMPORTERS.iter().map(|&(name, _)| name).collect()
+    } else {
+        selected
+    }
+}
+
+// Names of the items bindgen declared, top-level or in `extern` blocks. Whatever is in an `impl`
+// block can't be re-exported: the constants of the `NEWTYPE_ENUMS` and the methods of the
+// `dynamic-loading` struct.
+fn binding_items(bindings: &str) -> Vec<&str> {
+    const KINDS: &[&str] = &["struct ", "union ", "enum ", "type ", "const ", "static ", "fn "];
+
+    let mut in_impl = false;
+    let mut items: Vec<&str> = bindings
+        .lines()
+        .filter(|line| {
+            // bindgen's output is formatted, so an `impl` block ends at the first unindented `}`.
+            if line.starts_with("impl") {
+                in_impl = !line.trim_end().ends_with('}');
+                return false;
+            }
+            if in_impl {
+                in_impl = *line != "}";
+                return false;
+            }
+            true
+        })
+        .filter_map(|line| line.trim_start().strip_prefix("pub "))
+        .filter_map(|rest| KINDS.iter().find_map(|kind| rest.strip_prefix(kind)))
+        .filter_map(|rest| rest.split(|c: char| !(c.is_alphanumeric() || c == '_')).next())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    items.sort_unstable();
+    items.dedup();
+    items
 }
@@ -0,0 +1,69 @@
+//! Typed wrappers for assimp's bitmask enums.
+
+// The `as u32` is for MSVC, where the bindings' enum constants are `c_int` rather than `c_uint`.
+#![allow(clippy::unnecessary_cast)]
+
+use super::*;
+
+bitflags::bitflags! {
+    /// Post-processing steps, passed to `aiImportFile` and friends as `flags.bits()`.
+    pub struct PostProcessSteps: u32 {
+        const CALC_TANGENT_SPACE = aiPostProcessSteps_aiProcess_CalcTangentSpace as u32;
+        const JOIN_IDENTICAL_VERTICES = aiPostProcessSteps_aiProcess_JoinIdenticalVertices as u32;
+        const MAKE_LEFT_HANDED = aiPostProcessSteps_aiProcess_MakeLeftHanded as u32;
+        const TRIANGULATE = aiPostProcessSteps_aiProcess_Triangulate as u32;
+        const REMOVE_COMPONENT = aiPostProcessSteps_aiProcess_RemoveComponent as u32;
+        const GEN_NORMALS = aiPostProcessSteps_aiProcess_GenNormals as u32;
+        const GEN_SMOOTH_NORMALS = aiPostProcessSteps_aiProcess_GenSmoothNormals as u32;
+        const SPLIT_LARGE_MESHES = aiPostProcessSteps_aiProcess_SplitLargeMeshes as u32;
+        const PRE_TRANSFORM_VERTICES = aiPostProcessSteps_aiProcess_PreTransformVertices as u32;
+        const LIMIT_BONE_WEIGHTS = aiPostProcessSteps_aiProcess_LimitBoneWeights as u32;
+        const VALIDATE_DATA_STRUCTURE = aiPostProcessSteps_aiProcess_ValidateDataStructure as u32;
+        const IMPROVE_CACHE_LOCALITY = aiPostProcessSteps_aiProcess_ImproveCacheLocality as u32;
+        const REMOVE_REDUNDANT_MATERIALS = aiPostProcessSteps_aiProcess_RemoveRedundantMaterials as u32;
+        const FIX_INFACING_NORMALS = aiPostProcessSteps_aiProcess_FixInfacingNormals as u32;
+        const SORT_BY_PTYPE = aiPostProcessSteps_aiProcess_SortByPType as u32;
+        const FIND_DEGENERATES = aiPostProcessSteps_aiProcess_FindDegenerates as u32;
+        const FIND_INVALID_DATA = aiPostProcessSteps_aiProcess_FindInvalidData as u32;
+        const GEN_UV_COORDS = aiPostProcessSteps_aiProcess_GenUVCoords as u32;
+        const TRANSFORM_UV_COORDS = aiPostProcessSteps_aiProcess_TransformUVCoords as u32;
+        const FIND_INSTANCES = aiPostProcessSteps_aiProcess_FindInstances as u32;
+        const OPTIMIZE_MESHES = aiPostProcessSteps_aiProcess_OptimizeMeshes as u32;
+        const OPTIMIZE_GRAPH = aiPostProcessSteps_aiProcess_OptimizeGraph as u32;
+        const FLIP_UVS = aiPostProcessSteps_aiProcess_FlipUVs as u32;
+        const FLIP_WINDING_ORDER = aiPostProcessSteps_aiProcess_FlipWindingOrder as u32;
+        const SPLIT_BY_BONE_COUNT = aiPostProcessSteps_aiProcess_SplitByBoneCount as u32;
+        const DEBONE = aiPostProcessSteps_aiProcess_Debone as u32;
+        const GLOBAL_SCALE = aiPostProcessSteps_aiProcess_GlobalScale as u32;
+        const EMBED_TEXTURES = aiPostProcessSteps_aiProcess_EmbedTextures as u32;
+        const FORCE_GEN_NORMALS = aiPostProcessSteps_aiProcess_ForceGenNormals as u32;
+        const DROP_NORMALS = aiPostProcessSteps_aiProcess_DropNormals as u32;
+        const GEN_BOUNDING_BOXES = aiPostProcessSteps_aiProcess_GenBoundingBoxes as u32;
+
+        /// `aiProcess_ConvertToLeftHanded`
+        const CONVERT_TO_LEFT_HANDED = Self::MAKE_LEFT_HANDED.bits
+            | Self::FLIP_UVS.bits
+            | Self::FLIP_WINDING_ORDER.bits;
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags of a texture slot, as read from `AI_MATKEY_TEXFLAGS`.
+    pub struct TextureFlags: u32 {
+        const INVERT = aiTextureFlags_aiTextureFlags_Invert as u32;
+        const USE_ALPHA = aiTextureFlags_aiTextureFlags_UseAlpha as u32;
+        const IGNORE_ALPHA = aiTextureFlags_aiTextureFlags_IgnoreAlpha as u32;
+    }
+}
+
+impl From<PostProcessSteps> for u32 {
+    fn from(flags: PostProcessSteps) -> u32 {
+        flags.bits()
+    }
+}
+
+impl From<TextureFlags> for u32 {
+    fn from(flags: TextureFlags) -> u32 {
+        flags.bits()
+    }
+}
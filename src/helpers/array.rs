@@ -0,0 +1,115 @@
+//! Conversions between the value types and plain arrays of `ai_real`, e.g. for vertex buffers.
+//!
+//! Matrices convert in assimp's row-major order: `[a1, a2, a3, a4, b1, ...]` when flat, or one
+//! inner array per row (`a`, `b`, `c`, `d`) when nested. Column-major consumers, like OpenGL,
+//! need the matrix transposed first (e.g. with `aiTransposeMatrix4`).
+
+use crate::{ai_real, aiColor3D, aiColor4D, aiMatrix3x3, aiMatrix4x4, aiVector2D, aiVector3D};
+
+impl From<aiVector2D> for [ai_real; 2] {
+    fn from(v: aiVector2D) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl From<[ai_real; 2]> for aiVector2D {
+    fn from([x, y]: [ai_real; 2]) -> Self {
+        aiVector2D { x, y }
+    }
+}
+
+impl From<aiVector3D> for [ai_real; 3] {
+    fn from(v: aiVector3D) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<[ai_real; 3]> for aiVector3D {
+    fn from([x, y, z]: [ai_real; 3]) -> Self {
+        aiVector3D { x, y, z }
+    }
+}
+
+impl From<aiColor3D> for [ai_real; 3] {
+    fn from(c: aiColor3D) -> Self {
+        [c.r, c.g, c.b]
+    }
+}
+
+impl From<[ai_real; 3]> for aiColor3D {
+    fn from([r, g, b]: [ai_real; 3]) -> Self {
+        aiColor3D { r, g, b }
+    }
+}
+
+impl From<aiColor4D> for [ai_real; 4] {
+    fn from(c: aiColor4D) -> Self {
+        [c.r, c.g, c.b, c.a]
+    }
+}
+
+impl From<[ai_real; 4]> for aiColor4D {
+    fn from([r, g, b, a]: [ai_real; 4]) -> Self {
+        aiColor4D { r, g, b, a }
+    }
+}
+
+impl From<aiMatrix3x3> for [[ai_real; 3]; 3] {
+    fn from(m: aiMatrix3x3) -> Self {
+        [[m.a1, m.a2, m.a3], [m.b1, m.b2, m.b3], [m.c1, m.c2, m.c3]]
+    }
+}
+
+impl From<[[ai_real; 3]; 3]> for aiMatrix3x3 {
+    fn from([[a1, a2, a3], [b1, b2, b3], [c1, c2, c3]]: [[ai_real; 3]; 3]) -> Self {
+        aiMatrix3x3 { a1, a2, a3, b1, b2, b3, c1, c2, c3 }
+    }
+}
+
+impl From<aiMatrix3x3> for [ai_real; 9] {
+    fn from(m: aiMatrix3x3) -> Self {
+        [m.a1, m.a2, m.a3, m.b1, m.b2, m.b3, m.c1, m.c2, m.c3]
+    }
+}
+
+impl From<[ai_real; 9]> for aiMatrix3x3 {
+    fn from([a1, a2, a3, b1, b2, b3, c1, c2, c3]: [ai_real; 9]) -> Self {
+        aiMatrix3x3 { a1, a2, a3, b1, b2, b3, c1, c2, c3 }
+    }
+}
+
+impl From<aiMatrix4x4> for [[ai_real; 4]; 4] {
+    fn from(m: aiMatrix4x4) -> Self {
+        [
+            [m.a1, m.a2, m.a3, m.a4],
+            [m.b1, m.b2, m.b3, m.b4],
+            [m.c1, m.c2, m.c3, m.c4],
+            [m.d1, m.d2, m.d3, m.d4],
+        ]
+    }
+}
+
+impl From<[[ai_real; 4]; 4]> for aiMatrix4x4 {
+    fn from(
+        [[a1, a2, a3, a4], [b1, b2, b3, b4], [c1, c2, c3, c4], [d1, d2, d3, d4]]: [[ai_real; 4]; 4],
+    ) -> Self {
+        aiMatrix4x4 { a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4 }
+    }
+}
+
+impl From<aiMatrix4x4> for [ai_real; 16] {
+    fn from(m: aiMatrix4x4) -> Self {
+        [
+            m.a1, m.a2, m.a3, m.a4, m.b1, m.b2, m.b3, m.b4, m.c1, m.c2, m.c3, m.c4, m.d1, m.d2, m.d3,
+            m.d4,
+        ]
+    }
+}
+
+impl From<[ai_real; 16]> for aiMatrix4x4 {
+    fn from(
+        [a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4]: [ai_real; 16],
+    ) -> Self {
+        aiMatrix4x4 { a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4 }
+    }
+}
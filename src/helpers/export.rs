@@ -0,0 +1,71 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::{
+    aiExportScene, aiGetExportFormatCount, aiGetExportFormatDescription,
+    aiReleaseExportFormatDescription,
+};
+
+use super::import::to_c_string;
+use super::{AiReturn, Scene};
+
+/// One of the formats assimp can export to, as returned by [`export_formats`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExportFormat {
+    /// What to pass to [`export_scene`], e.g. `"collada"`.
+    pub id: String,
+    /// Human-readable name, e.g. `"COLLADA - Digital Asset Exchange Schema"`.
+    pub description: String,
+    /// Extension of the written files without the leading `.`, e.g. `"dae"`.
+    pub file_extension: String,
+}
+
+/// Every format assimp was built with an exporter for.
+pub fn export_formats() -> Vec<ExportFormat> {
+    let count = unsafe { aiGetExportFormatCount() };
+
+    (0..count)
+        .filter_map(|i| unsafe {
+            let desc = aiGetExportFormatDescription(i);
+            if desc.is_null() {
+                return None;
+            }
+
+            // Copied out right away, since the description is freed below.
+            let format = ExportFormat {
+                id: owned_string((*desc).id),
+                description: owned_string((*desc).description),
+                file_extension: owned_string((*desc).fileExtension),
+            };
+            aiReleaseExportFormatDescription(desc);
+            Some(format)
+        })
+        .collect()
+}
+
+/// Writes `scene` to `path` in the format with id `format_id` (e.g. `"gltf2"` or `"obj"`), with
+/// `flags` being post-processing steps to apply to a copy of the scene first.
+///
+/// Assimp's C API doesn't say why an export failed, the details are only logged (see
+/// [`attach_log`](super::attach_log)).
+pub fn export_scene(scene: &Scene, format_id: &str, path: &str, flags: u32) -> Result<(), String> {
+    if !export_formats().iter().any(|format| format.id == format_id) {
+        return Err(format!("{:?} isn't one of assimp's export formats", format_id));
+    }
+
+    let format = to_c_string(format_id)?;
+    let c_path = to_c_string(path)?;
+
+    let result = unsafe { aiExportScene(scene.as_ptr(), format.as_ptr(), c_path.as_ptr(), flags) };
+    AiReturn::from(result)
+        .into_result()
+        .map_err(|err| format!("Couldn't export to {:?} as {}: {}", path, format_id, err))
+}
+
+unsafe fn owned_string(string: *const c_char) -> String {
+    if string.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(string).to_string_lossy().into_owned()
+}
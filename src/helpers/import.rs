@@ -0,0 +1,106 @@
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::io::Read;
+use std::os::raw::{c_char, c_uint};
+use std::sync::{Mutex, PoisonError};
+
+use crate::{
+    aiGetErrorString, aiGetExtensionList, aiImportFile, aiImportFileFromMemory, aiScene, aiString,
+    MAXLEN,
+};
+
+use super::Scene;
+
+/// Imports the file at `path`, with `flags` being a combination of `aiPostProcessSteps`.
+///
+/// On failure the error is assimp's description of what went wrong. assimp keeps that in a single
+/// global, so this and the other `import_*` functions (except
+/// [`import_file_with_progress`](super::import_file_with_progress)) run one import at a time.
+pub fn import_file(path: &str, flags: u32) -> Result<Scene, String> {
+    let path = to_c_string(path)?;
+
+    unsafe { import_scene(|| aiImportFile(path.as_ptr(), flags)) }
+}
+
+/// Imports a file that's already in memory.
+///
+/// `hint` is the extension of the format (e.g. `"gltf"`), which assimp relies on for formats
+/// that can't be recognised by their contents alone.
+pub fn import_from_memory(data: &[u8], flags: u32, hint: Option<&str>) -> Result<Scene, String> {
+    let len = c_uint::try_from(data.len())
+        .map_err(|_| format!("{} bytes are too many to import at once", data.len()))?;
+    let hint = to_c_string(hint.unwrap_or(""))?;
+
+    unsafe {
+        import_scene(|| aiImportFileFromMemory(data.as_ptr() as *const c_char, len, flags, hint.as_ptr()))
+    }
+}
+
+/// Reads `reader` to the end and imports what it returned, like
+/// [`import_from_memory`] (which explains `hint`).
+///
+/// Everything is read into memory first, as that's what assimp needs for formats that refer
+/// back to earlier parts of the file. Reading stops with an error after `max_len` bytes, so an
+/// untrusted stream can't exhaust the memory.
+pub fn import_reader<R: Read>(
+    reader: R,
+    flags: u32,
+    hint: Option<&str>,
+    max_len: u64,
+) -> Result<Scene, String> {
+    let mut data = Vec::new();
+    reader
+        .take(max_len.saturating_add(1))
+        .read_to_end(&mut data)
+        .map_err(|err| format!("Couldn't read the data to import: {}", err))?;
+    if data.len() as u64 > max_len {
+        return Err(format!("The data to import is longer than its limit of {} bytes", max_len));
+    }
+
+    import_from_memory(&data, flags, hint)
+}
+
+/// The file extensions assimp can import, without the leading `.` (e.g. `"obj"`, `"fbx"`).
+pub fn supported_import_extensions() -> Vec<String> {
+    let mut list = aiString { length: 0, data: [0; MAXLEN as usize] };
+    unsafe { aiGetExtensionList(&mut list) };
+
+    // The list looks like `*.3ds;*.obj;*.dae`.
+    let mut extensions: Vec<String> = Vec::new();
+    for extension in list.to_string_lossy().split(';') {
+        let extension = extension.trim();
+        let extension = extension.strip_prefix("*.").unwrap_or(extension);
+        if !extension.is_empty() && !extensions.iter().any(|known| known == extension) {
+            extensions.push(extension.to_owned());
+        }
+    }
+
+    extensions
+}
+
+pub(super) fn to_c_string(string: &str) -> Result<CString, String> {
+    CString::new(string).map_err(|_| format!("{:?} contains a NUL byte", string))
+}
+
+// assimp's C API keeps the error of a failed import in one process-wide `std::string`, which the
+// next failing import overwrites, on whichever thread. The C API imports take turns so that each
+// one's error is read before another can replace it.
+static IMPORT_LOCK: Mutex<()> = Mutex::new(());
+
+// Runs `import`, which must return a scene from one of the C API's `aiImportFile*` functions,
+// and takes ownership of the scene or returns the import's error.
+pub(super) unsafe fn import_scene(import: impl FnOnce() -> *const aiScene) -> Result<Scene, String> {
+    // The lock guards no data, so there's nothing a panic could have left inconsistent.
+    let _turn = IMPORT_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    Scene::from_raw(import()).ok_or_else(last_error)
+}
+
+// The reason the last import failed, only meaningful while holding `IMPORT_LOCK`.
+fn last_error() -> String {
+    let error = unsafe { aiGetErrorString() };
+    if error.is_null() {
+        return String::new();
+    }
+
+    unsafe { CStr::from_ptr(error) }.to_string_lossy().into_owned()
+}
@@ -0,0 +1,213 @@
+use std::ffi::CStr;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use crate::{aiFile, aiFileIO, aiImportFileEx, aiOrigin, aiReturn};
+
+use super::import::{import_scene, to_c_string};
+use super::Scene;
+
+/// A file system assimp reads from instead of the real one, e.g. an archive or embedded assets.
+///
+/// Formats made of several files (`.obj` and its `.mtl`, `.gltf` and its buffers) open the rest
+/// through this as well, with paths relative to the first one.
+pub trait AssimpIo {
+    /// Opens `path`, or `None` if it doesn't exist.
+    fn open(&self, path: &str) -> Option<Box<dyn AssimpFile>>;
+}
+
+/// A file opened by an [`AssimpIo`].
+///
+/// Implemented for everything that's `Read + Seek`, such as `std::fs::File` or
+/// `std::io::Cursor`.
+pub trait AssimpFile {
+    /// Reads into `buf`, returning how many bytes were read. `Ok(0)` means the end of the file.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Moves the cursor, returning the new position from the start of the file.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64>;
+
+    /// The total length of the file in bytes.
+    fn size(&mut self) -> io::Result<u64>;
+
+    /// Writes from `buf`, returning how many bytes were written. Unsupported by default.
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("file is read-only"))
+    }
+
+    /// Flushes buffered writes.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: Read + Seek> AssimpFile for T {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(self, buf)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        Seek::seek(self, pos)
+    }
+
+    fn size(&mut self) -> io::Result<u64> {
+        let pos = Seek::seek(self, SeekFrom::Current(0))?;
+        let size = Seek::seek(self, SeekFrom::End(0))?;
+        Seek::seek(self, SeekFrom::Start(pos))?;
+        Ok(size)
+    }
+}
+
+/// Like [`import_file`](super::import_file), but opens `path` and everything it references
+/// through `io`. Imports run one at a time, so `io` must not start another one.
+pub fn import_file_with_io(path: &str, flags: u32, io: &dyn AssimpIo) -> Result<Scene, String> {
+    let path = to_c_string(path)?;
+
+    with_file_io(io, |file_io| unsafe { import_scene(|| aiImportFileEx(path.as_ptr(), flags, file_io)) })
+}
+
+// Calls `f` with an `aiFileIO` forwarding to `io`. Its `UserData` points to `io` on this stack
+// frame, so the `aiFileIO` must not be used once `f` returns. That's fine for assimp's import
+// and export functions, which close every file they opened before returning.
+pub(super) fn with_file_io<R>(io: &dyn AssimpIo, f: impl FnOnce(*mut aiFileIO) -> R) -> R {
+    // `&dyn AssimpIo` is a fat pointer, so pass a thin pointer to it instead.
+    let mut io = io;
+    let mut file_io = aiFileIO {
+        OpenProc: Some(open_proc),
+        CloseProc: Some(close_proc),
+        UserData: &mut io as *mut &dyn AssimpIo as *mut c_char,
+    };
+
+    f(&mut file_io)
+}
+
+// Panics can't unwind into assimp's C++ frames, so they are turned into assimp's error values.
+fn catch<R>(error: R, f: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(error)
+}
+
+unsafe fn opened_file<'a>(file: *mut aiFile) -> &'a mut Box<dyn AssimpFile> {
+    &mut *((*file).UserData as *mut Box<dyn AssimpFile>)
+}
+
+unsafe extern "C" fn open_proc(
+    file_io: *mut aiFileIO,
+    path: *const c_char,
+    _mode: *const c_char,
+) -> *mut aiFile {
+    let io = *((*file_io).UserData as *const &dyn AssimpIo);
+    let path = match CStr::from_ptr(path).to_str() {
+        Ok(path) => path,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match catch(None, || io.open(path)) {
+        Some(opened) => Box::into_raw(Box::new(aiFile {
+            ReadProc: Some(read_proc),
+            WriteProc: Some(write_proc),
+            TellProc: Some(tell_proc),
+            FileSizeProc: Some(size_proc),
+            SeekProc: Some(seek_proc),
+            FlushProc: Some(flush_proc),
+            UserData: Box::into_raw(Box::new(opened)) as *mut c_char,
+        })),
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn close_proc(_file_io: *mut aiFileIO, file: *mut aiFile) {
+    let file = Box::from_raw(file);
+    let opened = Box::from_raw(file.UserData as *mut Box<dyn AssimpFile>);
+    catch((), move || drop(opened));
+}
+
+// Like `fread`, reads up to `count` items of `size` bytes and returns how many were complete.
+unsafe extern "C" fn read_proc(
+    file: *mut aiFile,
+    buffer: *mut c_char,
+    size: usize,
+    count: usize,
+) -> usize {
+    let len = match size.checked_mul(count) {
+        Some(len) if len > 0 => len,
+        _ => return 0,
+    };
+    let buffer = slice::from_raw_parts_mut(buffer as *mut u8, len);
+    let file = opened_file(file);
+
+    catch(0, || {
+        let mut read = 0;
+        while read < len {
+            match file.read(&mut buffer[read..]) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        read / size
+    })
+}
+
+unsafe extern "C" fn write_proc(
+    file: *mut aiFile,
+    buffer: *const c_char,
+    size: usize,
+    count: usize,
+) -> usize {
+    let len = match size.checked_mul(count) {
+        Some(len) if len > 0 => len,
+        _ => return 0,
+    };
+    let buffer = slice::from_raw_parts(buffer as *const u8, len);
+    let file = opened_file(file);
+
+    catch(0, || {
+        let mut written = 0;
+        while written < len {
+            match file.write(&buffer[written..]) {
+                Ok(0) => break,
+                Ok(n) => written += n,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        written / size
+    })
+}
+
+unsafe extern "C" fn tell_proc(file: *mut aiFile) -> usize {
+    let file = opened_file(file);
+    catch(0, || file.seek(SeekFrom::Current(0)).unwrap_or(0) as usize)
+}
+
+unsafe extern "C" fn size_proc(file: *mut aiFile) -> usize {
+    let file = opened_file(file);
+    catch(0, || file.size().unwrap_or(0) as usize)
+}
+
+unsafe extern "C" fn seek_proc(file: *mut aiFile, offset: usize, origin: aiOrigin) -> aiReturn {
+    // Assimp passes backwards offsets as wrapped-around unsigned values.
+    let pos = match origin {
+        aiOrigin::aiOrigin_SET => SeekFrom::Start(offset as u64),
+        aiOrigin::aiOrigin_CUR => SeekFrom::Current(offset as isize as i64),
+        aiOrigin::aiOrigin_END => SeekFrom::End(offset as isize as i64),
+        _ => return aiReturn::aiReturn_FAILURE,
+    };
+
+    let file = opened_file(file);
+    catch(aiReturn::aiReturn_FAILURE, || match file.seek(pos) {
+        Ok(_) => aiReturn::aiReturn_SUCCESS,
+        Err(_) => aiReturn::aiReturn_FAILURE,
+    })
+}
+
+unsafe extern "C" fn flush_proc(file: *mut aiFile) {
+    let file = opened_file(file);
+    catch((), || {
+        let _ = file.flush();
+    });
+}
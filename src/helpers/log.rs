@@ -0,0 +1,154 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, PoisonError};
+
+use crate::{aiAttachLogStream, aiDetachLogStream, aiEnableVerboseLogging, aiLogStream};
+
+/// Severity of one of assimp's log messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Details only logged with verbose logging, which `attach_log` enables for this level.
+    Debug,
+    /// Progress of the import.
+    Info,
+    /// Problems assimp could work around, such as a missing texture or material library.
+    Warn,
+    /// Problems that made the import fail.
+    Error,
+}
+
+/// A callback attached to assimp's logger by [`attach_log`], detached again when dropped.
+#[must_use = "the callback is detached as soon as this is dropped"]
+#[derive(Debug)]
+pub struct LogStream {
+    stream: aiLogStream,
+    handler: *mut Handler,
+}
+
+impl Drop for LogStream {
+    fn drop(&mut self) {
+        unsafe {
+            // Assimp no longer calls the callback once this returns, so the handler can go.
+            aiDetachLogStream(&self.stream);
+            let handler = Box::from_raw(self.handler);
+            if handler.level == LogLevel::Debug {
+                set_verbose(false);
+            }
+        }
+    }
+}
+
+// The number of attached `LogLevel::Debug` streams. Verbose logging is a global switch, so it's
+// on while there's at least one of them.
+static VERBOSE_STREAMS: Mutex<usize> = Mutex::new(0);
+
+// Counts a `LogLevel::Debug` stream in or out, turning verbose logging on for the first and off
+// again after the last.
+fn set_verbose(attached: bool) {
+    let mut streams = VERBOSE_STREAMS.lock().unwrap_or_else(PoisonError::into_inner);
+    if attached {
+        *streams += 1;
+    } else {
+        *streams -= 1;
+    }
+
+    match (*streams, attached) {
+        // `AI_TRUE`
+        (1, true) => unsafe { aiEnableVerboseLogging(1) },
+        (0, false) => unsafe { aiEnableVerboseLogging(0) },
+        _ => {}
+    }
+}
+
+type Callback = dyn FnMut(LogLevel, &str) + Send;
+
+struct Handler {
+    level: LogLevel,
+    // Assimp's logger is global, so imports on different threads can log at the same time.
+    callback: Mutex<Box<Callback>>,
+}
+
+/// Calls `f` with every message assimp logs at `level` or above, until the returned
+/// [`LogStream`] is dropped.
+///
+/// Messages are passed without assimp's `"Info,  T0: "` prefix and trailing newline. The logger
+/// is shared by all imports in the process, so `f` is called from whichever thread is
+/// importing.
+///
+/// With [`LogLevel::Debug`] assimp's verbose logging is turned on, which is global as well: it
+/// applies to every log stream until the last `LogLevel::Debug` one is dropped.
+pub fn attach_log<F>(level: LogLevel, mut f: F) -> LogStream
+where
+    F: FnMut(&str) + Send + 'static,
+{
+    attach(level, Box::new(move |_, message| f(message)))
+}
+
+/// Forwards assimp's messages at `level` or above to the `log` crate, with `assimp` as the
+/// target.
+#[cfg(feature = "log")]
+pub fn attach_logger(level: LogLevel) -> LogStream {
+    attach(
+        level,
+        Box::new(|level, message| {
+            let level = match level {
+                LogLevel::Debug => log::Level::Debug,
+                LogLevel::Info => log::Level::Info,
+                LogLevel::Warn => log::Level::Warn,
+                LogLevel::Error => log::Level::Error,
+            };
+            log::log!(target: "assimp", level, "{}", message);
+        }),
+    )
+}
+
+fn attach(level: LogLevel, callback: Box<Callback>) -> LogStream {
+    let handler = Box::into_raw(Box::new(Handler { level, callback: Mutex::new(callback) }));
+    let stream = aiLogStream { callback: Some(log_callback), user: handler as *mut c_char };
+
+    if level == LogLevel::Debug {
+        set_verbose(true);
+    }
+    // Assimp keeps its own copy of `stream`.
+    unsafe { aiAttachLogStream(&stream) };
+
+    LogStream { stream, handler }
+}
+
+unsafe extern "C" fn log_callback(message: *const c_char, user: *mut c_char) {
+    let handler = &*(user as *const Handler);
+    let message = CStr::from_ptr(message).to_string_lossy();
+    let (level, message) = parse_message(&message);
+    if level < handler.level {
+        return;
+    }
+
+    // Panics can't unwind into assimp, so they just drop the message.
+    if let Ok(mut callback) = handler.callback.lock() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(level, message)));
+    }
+}
+
+// Splits `"Warn,  T0: message\n"` into its severity and message.
+fn parse_message(message: &str) -> (LogLevel, &str) {
+    let message = message.trim_end_matches(&['\r', '\n'][..]);
+    let (severity, rest) = match message.find(',') {
+        Some(comma) => (&message[..comma], &message[comma + 1..]),
+        None => return (LogLevel::Info, message),
+    };
+
+    let level = match severity {
+        "Debug" | "Verbose" => LogLevel::Debug,
+        "Info" => LogLevel::Info,
+        "Warn" => LogLevel::Warn,
+        "Error" => LogLevel::Error,
+        _ => return (LogLevel::Info, message),
+    };
+
+    // Followed by the id of the logging thread, as in `T0: `.
+    match rest.find(": ") {
+        Some(colon) => (level, &rest[colon + 2..]),
+        None => (level, rest.trim_start()),
+    }
+}
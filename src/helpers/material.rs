@@ -0,0 +1,77 @@
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use crate::{
+    ai_real, aiColor4D, aiGetMaterialColor, aiGetMaterialFloatArray, aiGetMaterialString,
+    aiGetMaterialTexture, aiGetMaterialTextureCount, aiMaterial, aiReturn, aiString, aiTextureType,
+    MaterialKey,
+};
+
+use super::AiReturn;
+
+impl aiMaterial {
+    /// The color property `key`, e.g. [`AI_MATKEY_COLOR_DIFFUSE`](crate::AI_MATKEY_COLOR_DIFFUSE),
+    /// or `None` if the material doesn't have it. Three-component colors get an alpha of 1.
+    pub fn color(&self, key: MaterialKey) -> Option<aiColor4D> {
+        let mut color = MaybeUninit::uninit();
+        let result =
+            unsafe { aiGetMaterialColor(self, key.key.as_ptr(), key.semantic, key.index, color.as_mut_ptr()) };
+        success(result).then(|| unsafe { color.assume_init() })
+    }
+
+    /// The scalar property `key`, e.g. [`AI_MATKEY_OPACITY`](crate::AI_MATKEY_OPACITY), or
+    /// `None` if the material doesn't have it. Integer properties are converted.
+    pub fn float(&self, key: MaterialKey) -> Option<ai_real> {
+        let mut value: ai_real = 0.0;
+        let mut max = 1;
+        let result =
+            unsafe { aiGetMaterialFloatArray(self, key.key.as_ptr(), key.semantic, key.index, &mut value, &mut max) };
+        (success(result) && max == 1).then_some(value)
+    }
+
+    /// The string property `key`, e.g. [`AI_MATKEY_NAME`](crate::AI_MATKEY_NAME), or `None` if
+    /// the material doesn't have it. Invalid UTF-8 is replaced with `U+FFFD`.
+    pub fn string(&self, key: MaterialKey) -> Option<String> {
+        let mut string = MaybeUninit::<aiString>::uninit();
+        let result =
+            unsafe { aiGetMaterialString(self, key.key.as_ptr(), key.semantic, key.index, string.as_mut_ptr()) };
+        success(result).then(|| String::from(unsafe { &string.assume_init() }))
+    }
+
+    /// The path of the `index`th texture of type `texture_type`, or `None` if there's no such
+    /// texture. Embedded textures have a path like `*0`, the index into `aiScene::mTextures`.
+    pub fn texture(&self, texture_type: aiTextureType, index: u32) -> Option<String> {
+        let mut path = MaybeUninit::<aiString>::uninit();
+        let result = unsafe {
+            aiGetMaterialTexture(
+                self,
+                texture_type,
+                index,
+                path.as_mut_ptr(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+        success(result).then(|| String::from(unsafe { &path.assume_init() }))
+    }
+
+    /// How many textures of type `texture_type` the material has, e.g. more than one diffuse
+    /// texture for blended layers.
+    pub fn texture_count(&self, texture_type: aiTextureType) -> u32 {
+        unsafe { aiGetMaterialTextureCount(self, texture_type) }
+    }
+
+    /// The paths of all textures of type `texture_type`, in index order. See
+    /// [`texture`](aiMaterial::texture) for embedded textures.
+    pub fn textures(&self, texture_type: aiTextureType) -> impl Iterator<Item = String> + '_ {
+        (0..self.texture_count(texture_type)).filter_map(move |index| self.texture(texture_type, index))
+    }
+}
+
+fn success(result: aiReturn) -> bool {
+    AiReturn::from(result) == AiReturn::Success
+}
@@ -0,0 +1,237 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{ai_real, aiColor4D, aiMatrix4x4, aiQuaternion, aiVector3D};
+
+impl aiVector3D {
+    /// The dot product of `self` and `other`.
+    pub fn dot(self, other: aiVector3D) -> ai_real {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product of `self` and `other`, following the right-hand rule like assimp's own
+    /// `operator^`: `x.cross(y)` is `z`.
+    pub fn cross(self, other: aiVector3D) -> aiVector3D {
+        aiVector3D {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// The Euclidean length of the vector.
+    pub fn length(self) -> ai_real {
+        self.dot(self).sqrt()
+    }
+}
+
+impl Add for aiVector3D {
+    type Output = aiVector3D;
+
+    fn add(self, other: aiVector3D) -> aiVector3D {
+        aiVector3D { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for aiVector3D {
+    type Output = aiVector3D;
+
+    fn sub(self, other: aiVector3D) -> aiVector3D {
+        aiVector3D { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl Mul<ai_real> for aiVector3D {
+    type Output = aiVector3D;
+
+    fn mul(self, factor: ai_real) -> aiVector3D {
+        aiVector3D { x: self.x * factor, y: self.y * factor, z: self.z * factor }
+    }
+}
+
+impl Neg for aiVector3D {
+    type Output = aiVector3D;
+
+    fn neg(self) -> aiVector3D {
+        aiVector3D { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+// Quaternions store their components as `w, x, y, z`, with the scalar part first, like assimp
+// does. Many other libraries (glam, nalgebra's storage, glTF) put it last instead, so convert by
+// field name rather than by position.
+
+impl aiQuaternion {
+    /// The rotation by `angle` radians around `axis`, counterclockwise when looking down the
+    /// axis towards the origin. `axis` doesn't have to be normalized.
+    pub fn from_axis_angle(axis: aiVector3D, angle: ai_real) -> aiQuaternion {
+        let axis = axis * (1.0 / axis.length());
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        aiQuaternion { w: cos, x: axis.x * sin, y: axis.y * sin, z: axis.z * sin }
+    }
+
+    /// The inverse of [`from_axis_angle`](aiQuaternion::from_axis_angle), for a unit quaternion:
+    /// the normalized axis and the angle in radians, between 0 and 2π. The identity has no axis
+    /// and gives the x axis with an angle of 0.
+    pub fn to_axis_angle(self) -> (aiVector3D, ai_real) {
+        let angle = 2.0 * self.w.clamp(-1.0, 1.0).acos();
+        let sin = (1.0 - self.w * self.w).max(0.0).sqrt();
+        if sin == 0.0 {
+            return (aiVector3D { x: 1.0, y: 0.0, z: 0.0 }, 0.0);
+        }
+        (aiVector3D { x: self.x / sin, y: self.y / sin, z: self.z / sin }, angle)
+    }
+
+    /// The rotation around the x, y and z axes in radians, in that order. Applied to a vector they
+    /// happen in the same order, x first, which makes the quaternion the product `z * y * x` of
+    /// the three rotations (yaw, pitch and roll). The y angle is between -π/2 and π/2, at
+    /// which point x and z rotate around the same axis and x is returned as 0.
+    pub fn to_euler(self) -> (ai_real, ai_real, ai_real) {
+        let aiQuaternion { w, x, y, z } = self;
+        let sin_y = 2.0 * (w * y - z * x);
+        // Close to the poles `asin` loses most of its precision, so snap to them.
+        if sin_y.abs() > 1.0 - 1e-6 {
+            let y_angle = (std::f64::consts::FRAC_PI_2 as ai_real).copysign(sin_y);
+            return (0.0, y_angle, -2.0 * sin_y.signum() * x.atan2(w));
+        }
+        let y_angle = sin_y.asin();
+        let x_angle = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let z_angle = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        (x_angle, y_angle, z_angle)
+    }
+
+    /// `self` scaled to unit length, which is what a rotation needs. A zero quaternion stays zero.
+    pub fn normalize(self) -> aiQuaternion {
+        let length = (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if length == 0.0 {
+            return self;
+        }
+        let factor = 1.0 / length;
+        aiQuaternion { w: self.w * factor, x: self.x * factor, y: self.y * factor, z: self.z * factor }
+    }
+}
+
+impl aiMatrix4x4 {
+    /// The identity transform.
+    pub fn identity() -> aiMatrix4x4 {
+        [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]].into()
+    }
+
+    /// Splits a transform into its translation, rotation and scale, like assimp's C++
+    /// `aiMatrix4x4::Decompose`, so that the matrix is `translation * rotation * scale`.
+    ///
+    /// The matrix is expected to be an affine transform without shear, which is what assimp's
+    /// node transforms are. A mirroring transform (negative determinant) can't be told apart
+    /// from one mirrored along other axes plus a rotation, so like assimp this negates all three
+    /// scale factors. A scale of zero along an axis leaves the rotation meaningless.
+    pub fn decompose(&self) -> (aiVector3D, aiQuaternion, aiVector3D) {
+        let m = self;
+        let translation = aiVector3D { x: m.a4, y: m.b4, z: m.c4 };
+
+        // The columns are the transformed axes, so their lengths are the scale.
+        let columns = [
+            aiVector3D { x: m.a1, y: m.b1, z: m.c1 },
+            aiVector3D { x: m.a2, y: m.b2, z: m.c2 },
+            aiVector3D { x: m.a3, y: m.b3, z: m.c3 },
+        ];
+        let mut scale = aiVector3D { x: columns[0].length(), y: columns[1].length(), z: columns[2].length() };
+        if columns[0].dot(columns[1].cross(columns[2])) < 0.0 {
+            scale = -scale;
+        }
+
+        let unscale = |column: aiVector3D, factor: ai_real| {
+            if factor == 0.0 {
+                column
+            } else {
+                column * (1.0 / factor)
+            }
+        };
+        let [x, y, z] = [unscale(columns[0], scale.x), unscale(columns[1], scale.y), unscale(columns[2], scale.z)];
+
+        (translation, rotation_quaternion(x, y, z), scale)
+    }
+}
+
+/// The matrix product: `a * b` applies `b` first and then `a`, as assimp's matrices transform
+/// column vectors. A node's world transform is `parent_world * node.mTransformation`.
+impl Mul for aiMatrix4x4 {
+    type Output = aiMatrix4x4;
+
+    fn mul(self, other: aiMatrix4x4) -> aiMatrix4x4 {
+        let (a, b): ([[ai_real; 4]; 4], [[ai_real; 4]; 4]) = (self.into(), other.into());
+        let mut product = [[0.0; 4]; 4];
+        for (row, a_row) in product.iter_mut().zip(&a) {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| a_row[k] * b[k][j]).sum();
+            }
+        }
+        product.into()
+    }
+}
+
+// The rotation taking the x, y and z axes to the orthonormal `x`, `y` and `z`, picking whichever
+// formula divides by the largest value for precision.
+fn rotation_quaternion(x: aiVector3D, y: aiVector3D, z: aiVector3D) -> aiQuaternion {
+    let trace = x.x + y.y + z.z;
+    if trace > 0.0 {
+        let s = (1.0 + trace).sqrt() * 2.0;
+        aiQuaternion { w: 0.25 * s, x: (y.z - z.y) / s, y: (z.x - x.z) / s, z: (x.y - y.x) / s }
+    } else if x.x > y.y && x.x > z.z {
+        let s = (1.0 + x.x - y.y - z.z).sqrt() * 2.0;
+        aiQuaternion { w: (y.z - z.y) / s, x: 0.25 * s, y: (x.y + y.x) / s, z: (z.x + x.z) / s }
+    } else if y.y > z.z {
+        let s = (1.0 + y.y - x.x - z.z).sqrt() * 2.0;
+        aiQuaternion { w: (z.x - x.z) / s, x: (x.y + y.x) / s, y: 0.25 * s, z: (y.z + z.y) / s }
+    } else {
+        let s = (1.0 + z.z - x.x - y.y).sqrt() * 2.0;
+        aiQuaternion { w: (x.y - y.x) / s, x: (z.x + x.z) / s, y: (y.z + z.y) / s, z: 0.25 * s }
+    }
+}
+
+/// The Hamilton product: `a * b` rotates by `b` first and then by `a`.
+impl Mul for aiQuaternion {
+    type Output = aiQuaternion;
+
+    fn mul(self, other: aiQuaternion) -> aiQuaternion {
+        let (a, b) = (self, other);
+        aiQuaternion {
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        }
+    }
+}
+
+// Colors are combined per channel, alpha included.
+
+impl Add for aiColor4D {
+    type Output = aiColor4D;
+
+    fn add(self, other: aiColor4D) -> aiColor4D {
+        aiColor4D { r: self.r + other.r, g: self.g + other.g, b: self.b + other.b, a: self.a + other.a }
+    }
+}
+
+impl Sub for aiColor4D {
+    type Output = aiColor4D;
+
+    fn sub(self, other: aiColor4D) -> aiColor4D {
+        aiColor4D { r: self.r - other.r, g: self.g - other.g, b: self.b - other.b, a: self.a - other.a }
+    }
+}
+
+impl Mul for aiColor4D {
+    type Output = aiColor4D;
+
+    fn mul(self, other: aiColor4D) -> aiColor4D {
+        aiColor4D { r: self.r * other.r, g: self.g * other.g, b: self.b * other.b, a: self.a * other.a }
+    }
+}
+
+impl Mul<ai_real> for aiColor4D {
+    type Output = aiColor4D;
+
+    fn mul(self, factor: ai_real) -> aiColor4D {
+        aiColor4D { r: self.r * factor, g: self.g * factor, b: self.b * factor, a: self.a * factor }
+    }
+}
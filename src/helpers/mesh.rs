@@ -0,0 +1,49 @@
+use crate::{aiFace, aiMesh, aiVector3D};
+
+use super::raw::slice;
+
+impl aiMesh {
+    /// The vertex positions, `mNumVertices` of them.
+    pub fn vertices(&self) -> &[aiVector3D] {
+        unsafe { slice(self.mVertices, self.mNumVertices) }
+    }
+
+    /// The vertex normals, or `None` if the mesh has none.
+    pub fn normals(&self) -> Option<&[aiVector3D]> {
+        self.vertex_attribute(self.mNormals)
+    }
+
+    /// The faces, `mNumFaces` of them.
+    pub fn faces(&self) -> &[aiFace] {
+        unsafe { slice(self.mFaces, self.mNumFaces) }
+    }
+
+    /// The indices of every face, concatenated. For a triangulated mesh (see
+    /// `aiProcess_Triangulate`) that's a triangle list ready to be uploaded as an index
+    /// buffer.
+    pub fn all_indices(&self) -> Vec<u32> {
+        self.faces().iter().flat_map(|face| face.indices().iter().copied()).collect()
+    }
+
+    /// The texture coordinates of UV channel `channel`, or `None` if the mesh doesn't have that
+    /// channel. Only the first `mNumUVComponents[channel]` components of each are meaningful.
+    pub fn uv_channel(&self, channel: usize) -> Option<&[aiVector3D]> {
+        self.vertex_attribute(*self.mTextureCoords.get(channel)?)
+    }
+
+    // One element per vertex, if the attribute is present at all.
+    fn vertex_attribute(&self, ptr: *mut aiVector3D) -> Option<&[aiVector3D]> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { slice(ptr, self.mNumVertices) })
+        }
+    }
+}
+
+impl aiFace {
+    /// The vertex indices of the face, empty for a degenerate face without any.
+    pub fn indices(&self) -> &[u32] {
+        unsafe { slice(self.mIndices, self.mNumIndices) }
+    }
+}
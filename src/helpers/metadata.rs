@@ -0,0 +1,89 @@
+use std::os::raw::c_void;
+
+use crate::{aiMetadata, aiMetadataEntry, aiMetadataType, aiNode, aiString, aiVector3D};
+
+use super::raw::slice;
+
+// Tags added after 5.0, which the bindings of an older assimp don't have constants for. The
+// values only ever get appended to, so they're the same in every version that knows them. `as _`
+// as the newtype wraps an `int` rather than an `unsigned int` on MSVC.
+const AI_AIMETADATA: aiMetadataType = aiMetadataType(7 as _);
+const AI_INT64: aiMetadataType = aiMetadataType(8 as _);
+const AI_UINT32: aiMetadataType = aiMetadataType(9 as _);
+
+/// A decoded metadata value, see [`aiMetadata::entries`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    Bool(bool),
+    Int32(i32),
+    UInt64(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Vector3D(aiVector3D),
+    /// Nested metadata, as glTF and FBX use for structured properties (assimp 5.1 and later).
+    Metadata(Vec<(String, MetadataValue)>),
+    Int64(i64),
+    UInt32(u32),
+    /// A type this crate doesn't know about, or an entry without a value, with its raw
+    /// `aiMetadataType`.
+    Unknown(u32),
+}
+
+impl aiMetadata {
+    /// The key-value pairs, decoded according to each entry's `aiMetadataType`. Invalid UTF-8 in
+    /// keys and strings is replaced with `U+FFFD`.
+    pub fn entries(&self) -> impl Iterator<Item = (String, MetadataValue)> + '_ {
+        self.keys().iter().enumerate().map(move |(i, key)| {
+            let value = unsafe { decode(self.mValues.add(i)) };
+            (String::from(key), value)
+        })
+    }
+
+    /// The value of `key`, e.g. `UnitScaleFactor` or `UpAxis`, or `None` if there's no such
+    /// entry.
+    pub fn get(&self, key: &str) -> Option<MetadataValue> {
+        let i = self.keys().iter().position(|k| k.as_bytes() == key.as_bytes())?;
+        Some(unsafe { decode(self.mValues.add(i)) })
+    }
+
+    // The keys, or none if there are no values to go with them.
+    fn keys(&self) -> &[aiString] {
+        let len = if self.mValues.is_null() { 0 } else { self.mNumProperties };
+        unsafe { slice(self.mKeys, len) }
+    }
+}
+
+impl aiNode {
+    /// The node's metadata, if it has any.
+    pub fn metadata(&self) -> Option<&aiMetadata> {
+        unsafe { self.mMetaData.as_ref() }
+    }
+}
+
+// `aiMetadataType` is a newtype rather than a Rust enum, so a tag the bindings have no constant
+// for (e.g. from a newer assimp) is just another value. The `as u32` is only needed on MSVC.
+#[allow(clippy::unnecessary_cast)]
+unsafe fn decode(entry: *const aiMetadataEntry) -> MetadataValue {
+    let tag = (*entry).mType;
+    let data: *const c_void = (*entry).mData;
+    if data.is_null() {
+        return MetadataValue::Unknown(tag.0 as u32);
+    }
+
+    match tag {
+        // A C++ `bool`, which is a single byte.
+        aiMetadataType::AI_BOOL => MetadataValue::Bool(*data.cast::<u8>() != 0),
+        aiMetadataType::AI_INT32 => MetadataValue::Int32(*data.cast()),
+        aiMetadataType::AI_UINT64 => MetadataValue::UInt64(*data.cast()),
+        // Always single precision, unlike `ai_real`.
+        aiMetadataType::AI_FLOAT => MetadataValue::Float(*data.cast()),
+        aiMetadataType::AI_DOUBLE => MetadataValue::Double(*data.cast()),
+        aiMetadataType::AI_AISTRING => MetadataValue::String(String::from(&*data.cast::<aiString>())),
+        aiMetadataType::AI_AIVECTOR3D => MetadataValue::Vector3D(*data.cast()),
+        AI_AIMETADATA => MetadataValue::Metadata((*data.cast::<aiMetadata>()).entries().collect()),
+        AI_INT64 => MetadataValue::Int64(*data.cast()),
+        AI_UINT32 => MetadataValue::UInt32(*data.cast()),
+        aiMetadataType(other) => MetadataValue::Unknown(other as u32),
+    }
+}
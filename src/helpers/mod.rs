@@ -0,0 +1,36 @@
+//! Thin safe conveniences on top of the raw bindings, enabled by the `helpers` feature.
+
+mod array;
+// Assimp built with `ASSIMP_NO_EXPORT` doesn't have the export functions at all.
+#[cfg(not(feature = "no-exporters"))]
+mod export;
+mod import;
+mod io;
+mod log;
+mod material;
+mod math;
+mod mesh;
+mod metadata;
+mod progress;
+mod properties;
+mod raw;
+mod result;
+mod scene;
+mod string;
+mod texture;
+mod threads;
+
+#[cfg(not(feature = "no-exporters"))]
+pub use self::export::{export_formats, export_scene, ExportFormat};
+pub use self::import::{import_file, import_from_memory, import_reader, supported_import_extensions};
+pub use self::io::{import_file_with_io, AssimpFile, AssimpIo};
+#[cfg(feature = "log")]
+pub use self::log::attach_logger;
+pub use self::log::{attach_log, LogLevel, LogStream};
+pub use self::metadata::MetadataValue;
+pub use self::progress::import_file_with_progress;
+pub use self::properties::PropertyStore;
+pub use self::result::{AiError, AiReturn};
+pub use self::scene::Scene;
+pub use self::string::TooLong;
+pub use self::texture::TextureData;
@@ -0,0 +1,63 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_uint, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::aiScene;
+
+use super::import::to_c_string;
+use super::Scene;
+
+// `src/shim/progress.cpp`
+extern "C" {
+    fn assimp_sys_import_with_progress(
+        path: *const c_char,
+        flags: c_uint,
+        callback: unsafe extern "C" fn(*mut c_void, f32) -> bool,
+        user_data: *mut c_void,
+        error: *mut c_char,
+        error_len: usize,
+    ) -> *const aiScene;
+}
+
+/// Like [`import_file`](super::import_file), but calls `progress` periodically during the
+/// import.
+///
+/// `progress` receives an estimate of how far along the import is, from `0.0` to `1.0`, or a
+/// negative value when there's no estimate. How often it's called depends on the format, most
+/// importers report progress while reading the file and then once per post-processing step.
+///
+/// Returning `false` (or panicking) asks assimp to cancel the import, in which case it fails.
+/// This is only a request though: assimp checks it at few points, and many importers run to
+/// completion regardless.
+///
+/// The C API has no way to report progress, so this goes through a small C++ wrapper around
+/// `Assimp::Importer` with a custom `Assimp::ProgressHandler` instead of `aiImportFile`.
+pub fn import_file_with_progress<F>(path: &str, flags: u32, mut progress: F) -> Result<Scene, String>
+where
+    F: FnMut(f32) -> bool,
+{
+    let path = to_c_string(path)?;
+    let mut error = [0 as c_char; 1024];
+
+    let scene = unsafe {
+        assimp_sys_import_with_progress(
+            path.as_ptr(),
+            flags,
+            progress_callback::<F>,
+            &mut progress as *mut F as *mut c_void,
+            error.as_mut_ptr(),
+            error.len(),
+        )
+    };
+
+    unsafe { Scene::from_raw(scene) }
+        .ok_or_else(|| unsafe { CStr::from_ptr(error.as_ptr()) }.to_string_lossy().into_owned())
+}
+
+unsafe extern "C" fn progress_callback<F>(user_data: *mut c_void, percentage: f32) -> bool
+where
+    F: FnMut(f32) -> bool,
+{
+    let progress = &mut *(user_data as *mut F);
+    panic::catch_unwind(AssertUnwindSafe(|| progress(percentage))).unwrap_or(false)
+}
@@ -0,0 +1,83 @@
+use crate::{
+    ai_real, aiCreatePropertyStore, aiImportFileExWithProperties, aiPropertyStore,
+    aiReleasePropertyStore, aiSetImportPropertyFloat, aiSetImportPropertyInteger,
+    aiSetImportPropertyString, aiString,
+};
+
+use super::import::{import_scene, to_c_string};
+use super::Scene;
+
+/// Import settings, the `AI_CONFIG_*` options from assimp's `config.h`.
+///
+/// Names are passed without the `AI_CONFIG_` prefix, as in e.g. `"PP_SLM_VERTEX_LIMIT"`.
+/// The setters can be chained:
+///
+/// ```no_run
+/// # fn main() -> Result<(), String> {
+/// let mut properties = assimp_sys::PropertyStore::new();
+/// properties.set_int("PP_SLM_VERTEX_LIMIT", 10_000)?.set_bool("FAVOUR_SPEED", true)?;
+/// let scene = properties.import_file("model.fbx", 0)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PropertyStore {
+    ptr: *mut aiPropertyStore,
+}
+
+impl PropertyStore {
+    /// An empty store, i.e. assimp's defaults for everything.
+    pub fn new() -> PropertyStore {
+        PropertyStore { ptr: unsafe { aiCreatePropertyStore() } }
+    }
+
+    /// Sets an integer property.
+    pub fn set_int(&mut self, name: &str, value: i32) -> Result<&mut Self, String> {
+        let name = to_c_string(name)?;
+        unsafe { aiSetImportPropertyInteger(self.ptr, name.as_ptr(), value) };
+        Ok(self)
+    }
+
+    /// Sets a floating point property.
+    pub fn set_float(&mut self, name: &str, value: ai_real) -> Result<&mut Self, String> {
+        let name = to_c_string(name)?;
+        unsafe { aiSetImportPropertyFloat(self.ptr, name.as_ptr(), value) };
+        Ok(self)
+    }
+
+    /// Sets a string property. Fails if `value` doesn't fit into an `aiString`.
+    pub fn set_string(&mut self, name: &str, value: &str) -> Result<&mut Self, String> {
+        let name = to_c_string(name)?;
+        let value = aiString::from_bytes(value.as_bytes()).map_err(|err| err.to_string())?;
+        unsafe { aiSetImportPropertyString(self.ptr, name.as_ptr(), &value) };
+        Ok(self)
+    }
+
+    /// Sets a boolean property, which assimp stores as an integer.
+    pub fn set_bool(&mut self, name: &str, value: bool) -> Result<&mut Self, String> {
+        self.set_int(name, value as i32)
+    }
+
+    /// Like [`import_file`](super::import_file), but with these settings.
+    pub fn import_file(&self, path: &str, flags: u32) -> Result<Scene, String> {
+        let path = to_c_string(path)?;
+
+        unsafe {
+            import_scene(|| {
+                aiImportFileExWithProperties(path.as_ptr(), flags, std::ptr::null_mut(), self.ptr)
+            })
+        }
+    }
+}
+
+impl Default for PropertyStore {
+    fn default() -> Self {
+        PropertyStore::new()
+    }
+}
+
+impl Drop for PropertyStore {
+    fn drop(&mut self) {
+        unsafe { aiReleasePropertyStore(self.ptr) }
+    }
+}
@@ -0,0 +1,21 @@
+use std::os::raw::c_uint;
+use std::slice;
+
+// Assimp's arrays are a count plus a pointer that's null when the count is zero (or, for
+// optional attributes, when they're missing), which `slice::from_raw_parts` doesn't allow.
+
+// The `len` elements at `ptr`, or nothing if `ptr` is null. Unless null, `ptr` has to point to
+// `len` elements that are valid for `'a`.
+pub(super) unsafe fn slice<'a, T>(ptr: *const T, len: c_uint) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+// The non-null elements of a `T**` array of `len` pointers. Unless null, `array` has to point
+// to `len` pointers that are each null or valid for `'a`.
+pub(super) unsafe fn pointers<'a, T: 'a>(array: *mut *mut T, len: c_uint) -> impl Iterator<Item = &'a T> {
+    slice(array as *const *mut T, len).iter().filter_map(|&ptr| ptr.as_ref())
+}
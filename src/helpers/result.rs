@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::aiReturn;
+
+/// The outcome of an assimp call returning `aiReturn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AiReturn {
+    /// `aiReturn_SUCCESS`
+    Success,
+    /// `aiReturn_FAILURE`
+    Failure,
+    /// `aiReturn_OUTOFMEMORY`
+    OutOfMemory,
+}
+
+impl AiReturn {
+    /// `Ok` on success, so that calls can be chained with `?`.
+    pub fn into_result(self) -> Result<(), AiError> {
+        match self {
+            AiReturn::Success => Ok(()),
+            AiReturn::Failure => Err(AiError::Failure),
+            AiReturn::OutOfMemory => Err(AiError::OutOfMemory),
+        }
+    }
+}
+
+impl From<aiReturn> for AiReturn {
+    fn from(value: aiReturn) -> Self {
+        match value {
+            aiReturn::aiReturn_SUCCESS => AiReturn::Success,
+            aiReturn::aiReturn_OUTOFMEMORY => AiReturn::OutOfMemory,
+            // `aiReturn_FAILURE`, or a code the bindings don't know about.
+            _ => AiReturn::Failure,
+        }
+    }
+}
+
+impl From<AiReturn> for aiReturn {
+    fn from(value: AiReturn) -> Self {
+        match value {
+            AiReturn::Success => aiReturn::aiReturn_SUCCESS,
+            AiReturn::Failure => aiReturn::aiReturn_FAILURE,
+            AiReturn::OutOfMemory => aiReturn::aiReturn_OUTOFMEMORY,
+        }
+    }
+}
+
+/// An `aiReturn` other than `aiReturn_SUCCESS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AiError {
+    /// `aiReturn_FAILURE`
+    Failure,
+    /// `aiReturn_OUTOFMEMORY`
+    OutOfMemory,
+}
+
+impl fmt::Display for AiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AiError::Failure => f.write_str("assimp call failed (aiReturn_FAILURE)"),
+            AiError::OutOfMemory => f.write_str("assimp ran out of memory (aiReturn_OUTOFMEMORY)"),
+        }
+    }
+}
+
+impl Error for AiError {}
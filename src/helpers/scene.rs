@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use std::ptr::NonNull;
+
+use crate::{aiMaterial, aiMatrix4x4, aiMesh, aiMetadata, aiNode, aiReleaseImport, aiScene, aiTexture};
+
+use super::raw::pointers;
+
+/// An imported scene, released with `aiReleaseImport` when dropped.
+#[derive(Debug)]
+pub struct Scene {
+    ptr: NonNull<aiScene>,
+}
+
+impl Scene {
+    /// Takes ownership of a scene returned by one of the `aiImportFile*` functions, or `None` if
+    /// `ptr` is null (i.e. the import failed).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` has to come from assimp's C importer functions and must not be released elsewhere.
+    pub unsafe fn from_raw(ptr: *const aiScene) -> Option<Scene> {
+        NonNull::new(ptr as *mut aiScene).map(|ptr| Scene { ptr })
+    }
+
+    /// The scene pointer, which stays valid for as long as `self` is alive.
+    pub fn as_ptr(&self) -> *const aiScene {
+        self.ptr.as_ptr()
+    }
+
+    /// The scene's meshes, in the order `aiNode::mMeshes` indexes them.
+    pub fn meshes(&self) -> impl Iterator<Item = &aiMesh> {
+        let scene = self.as_ref();
+        unsafe { pointers(scene.mMeshes, scene.mNumMeshes) }
+    }
+
+    /// The scene's materials, in the order `aiMesh::mMaterialIndex` indexes them.
+    pub fn materials(&self) -> impl Iterator<Item = &aiMaterial> {
+        let scene = self.as_ref();
+        unsafe { pointers(scene.mMaterials, scene.mNumMaterials) }
+    }
+
+    /// The textures embedded in the file, e.g. by glTF or FBX. Materials refer to them with
+    /// `*0`, `*1` and so on as the texture path, counting in this order.
+    pub fn embedded_textures(&self) -> impl Iterator<Item = &aiTexture> {
+        let scene = self.as_ref();
+        unsafe { pointers(scene.mTextures, scene.mNumTextures) }
+    }
+
+    /// The scene's metadata, such as the `UnitScaleFactor` and `UpAxis` of FBX files, if the
+    /// importer provided any.
+    pub fn metadata(&self) -> Option<&aiMetadata> {
+        unsafe { self.as_ref().mMetaData.as_ref() }
+    }
+
+    /// Every node of the hierarchy, starting with the root and visiting each node before its
+    /// children (depth first).
+    pub fn nodes(&self) -> impl Iterator<Item = &aiNode> {
+        let root = unsafe { self.as_ref().mRootNode.as_ref() };
+        Nodes { stack: root.into_iter().collect() }
+    }
+
+    /// Calls `f` with every node and its world transform, the product of its own and all of its
+    /// ancestors' `mTransformation` (`root * ... * parent * node`). The nodes are visited in the
+    /// same order as [`nodes`](Scene::nodes).
+    ///
+    /// A node that's reached a second time, which only a broken hierarchy with a cycle or a
+    /// shared child would do, is skipped along with its children, so that this always ends.
+    pub fn walk_nodes(&self, mut f: impl FnMut(&aiNode, aiMatrix4x4)) {
+        let root = unsafe { self.as_ref().mRootNode.as_ref() };
+        let mut stack: Vec<_> = root.map(|root| (root, aiMatrix4x4::identity())).into_iter().collect();
+        let mut visited = HashSet::new();
+        while let Some((node, parent)) = stack.pop() {
+            if !visited.insert(node as *const aiNode) {
+                continue;
+            }
+
+            let world = parent * node.mTransformation;
+            f(node, world);
+
+            // Reversed like in `Nodes`, so that the first child is visited next.
+            let children = unsafe { pointers(node.mChildren, node.mNumChildren) };
+            let first = stack.len();
+            stack.extend(children.map(|child| (child, world)));
+            stack[first..].reverse();
+        }
+    }
+}
+
+// Walks the node hierarchy with an explicit stack, as it can be arbitrarily deep.
+struct Nodes<'a> {
+    stack: Vec<&'a aiNode>,
+}
+
+impl<'a> Iterator for Nodes<'a> {
+    type Item = &'a aiNode;
+
+    fn next(&mut self) -> Option<&'a aiNode> {
+        let node = self.stack.pop()?;
+        // Reversed, so that the first child is visited next.
+        let children = unsafe { pointers(node.mChildren, node.mNumChildren) };
+        let first = self.stack.len();
+        self.stack.extend(children);
+        self.stack[first..].reverse();
+        Some(node)
+    }
+}
+
+impl AsRef<aiScene> for Scene {
+    fn as_ref(&self) -> &aiScene {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl Drop for Scene {
+    fn drop(&mut self) {
+        unsafe { aiReleaseImport(self.ptr.as_ptr()) }
+    }
+}
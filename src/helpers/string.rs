@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use crate::{aiString, MAXLEN};
+
+/// Returned by [`aiString::from_bytes`] when the input doesn't fit into the fixed-size buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooLong {
+    /// Length of the rejected input, in bytes.
+    pub len: usize,
+}
+
+impl fmt::Display for TooLong {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes don't fit into an aiString, which holds at most {}",
+            self.len,
+            MAXLEN - 1
+        )
+    }
+}
+
+impl Error for TooLong {}
+
+impl aiString {
+    /// Copies `bytes` into a new `aiString`.
+    ///
+    /// Fails if `bytes` doesn't leave room for the terminating NUL byte that assimp expects.
+    pub fn from_bytes(bytes: &[u8]) -> Result<aiString, TooLong> {
+        aiString::with_bytes(bytes).ok_or(TooLong { len: bytes.len() })
+    }
+
+    /// The first `length` bytes of `data`, i.e. the string's contents without the NUL
+    /// terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.valid_bytes()
+    }
+
+    /// Decodes the string as UTF-8, replacing invalid sequences with `U+FFFD`.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.as_bytes())
+    }
+}
+
+impl From<&aiString> for String {
+    fn from(string: &aiString) -> String {
+        string.to_string_lossy().into_owned()
+    }
+}
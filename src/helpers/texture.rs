@@ -0,0 +1,36 @@
+use crate::{aiTexel, aiTexture};
+
+use super::raw::slice;
+
+/// The contents of an embedded texture, see [`aiTexture::data`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextureData<'a> {
+    /// An image file as it was embedded, e.g. a PNG or JPEG. `aiTexture::format_hint` is its
+    /// extension.
+    Compressed(&'a [u8]),
+    /// Decoded texels, row by row.
+    Raw { width: u32, height: u32, texels: &'a [aiTexel] },
+}
+
+impl aiTexture {
+    /// The texture's contents. A `mHeight` of 0 means `pcData` holds `mWidth` bytes of an image
+    /// file instead of `mWidth * mHeight` texels.
+    pub fn data(&self) -> TextureData<'_> {
+        if self.mHeight == 0 {
+            return TextureData::Compressed(unsafe { slice(self.pcData as *const u8, self.mWidth) });
+        }
+
+        // No real texture has more than `u32::MAX` texels, a corrupt one gets none.
+        let len = self.mWidth.checked_mul(self.mHeight).unwrap_or(0);
+        let texels = unsafe { slice(self.pcData, len) };
+        TextureData::Raw { width: self.mWidth, height: self.mHeight, texels }
+    }
+
+    /// The file extension of a compressed texture (e.g. `png` or `jpg`), or a description of
+    /// the texel layout like `argb8888` for a raw one. Empty if the importer didn't set it.
+    pub fn format_hint(&self) -> String {
+        let len = self.achFormatHint.iter().position(|&c| c == 0).unwrap_or(self.achFormatHint.len());
+        let bytes: Vec<u8> = self.achFormatHint[..len].iter().map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    aiColor3D, aiColor4D, aiMatrix3x3, aiMatrix4x4, aiQuaternion, aiString, aiVector2D, aiVector3D,
+};
+
+// The value types only hold numbers, so they're `Send` and `Sync` without any `unsafe impl` and
+// vertex data copied out of a scene can be processed on other threads. This keeps it that way.
+//
+// The structs holding pointers (`aiScene`, `aiMesh`, `aiNode`, `aiMaterial`, ...) deliberately
+// stay neither: the memory they point to belongs to the scene assimp allocated, and nothing stops
+// it from being released (or written to) on another thread while the pointers are still around.
+// Copy the data out through the slice accessors first.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<aiVector2D>();
+    assert_send_sync::<aiVector3D>();
+    assert_send_sync::<aiColor3D>();
+    assert_send_sync::<aiColor4D>();
+    assert_send_sync::<aiQuaternion>();
+    assert_send_sync::<aiMatrix3x3>();
+    assert_send_sync::<aiMatrix4x4>();
+    assert_send_sync::<aiString>();
+};
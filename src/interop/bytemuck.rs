@@ -0,0 +1,50 @@
+//! Only structs made purely of `ai_real` or bytes get the impls, so there's no padding to worry
+//! about:
+//!
+//! ```
+//! fn pod<T: bytemuck::Pod>() {}
+//! pod::<assimp_sys::aiVector3D>();
+//! ```
+//!
+//! Anything holding pointers or an `aiString` is left out:
+//!
+//! ```compile_fail
+//! fn pod<T: bytemuck::Pod>() {}
+//! pod::<assimp_sys::aiFace>();
+//! ```
+//!
+//! ```compile_fail
+//! fn zeroable<T: bytemuck::Zeroable>() {}
+//! zeroable::<assimp_sys::aiString>();
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+use core::mem::{align_of, size_of};
+
+use crate::{
+    ai_real, aiAABB, aiColor3D, aiColor4D, aiMatrix3x3, aiMatrix4x4, aiQuaternion, aiTexel,
+    aiVector2D, aiVector3D,
+};
+
+macro_rules! pod {
+    ($($ty:ident: [$field:ty; $n:expr]),* $(,)?) => {$(
+        // Layouts come from the headers, so check they really are `$n` packed fields.
+        const _: () = assert!(size_of::<$ty>() == $n * size_of::<$field>());
+        const _: () = assert!(align_of::<$ty>() == align_of::<$field>());
+
+        unsafe impl Zeroable for $ty {}
+        unsafe impl Pod for $ty {}
+    )*};
+}
+
+pod! {
+    aiVector2D: [ai_real; 2],
+    aiVector3D: [ai_real; 3],
+    aiColor3D: [ai_real; 3],
+    aiColor4D: [ai_real; 4],
+    aiQuaternion: [ai_real; 4],
+    aiMatrix3x3: [ai_real; 9],
+    aiMatrix4x4: [ai_real; 16],
+    aiAABB: [ai_real; 6],
+    aiTexel: [u8; 4],
+}
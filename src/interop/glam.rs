@@ -0,0 +1,75 @@
+// glam has separate types per precision, use whichever matches `ai_real`.
+#[cfg(feature = "double-precision")]
+use glam::{DMat3 as Mat3, DMat4 as Mat4, DQuat as Quat, DVec2 as Vec2, DVec3 as Vec3};
+#[cfg(not(feature = "double-precision"))]
+use glam::{Mat3, Mat4, Quat, Vec2, Vec3};
+
+use crate::{ai_real, aiMatrix3x3, aiMatrix4x4, aiQuaternion, aiVector2D, aiVector3D};
+
+impl From<aiVector2D> for Vec2 {
+    fn from(v: aiVector2D) -> Self {
+        Vec2::new(v.x, v.y)
+    }
+}
+
+impl From<Vec2> for aiVector2D {
+    fn from(v: Vec2) -> Self {
+        aiVector2D { x: v.x, y: v.y }
+    }
+}
+
+impl From<aiVector3D> for Vec3 {
+    fn from(v: aiVector3D) -> Self {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for aiVector3D {
+    fn from(v: Vec3) -> Self {
+        aiVector3D { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+// Assimp stores the scalar part first (`w, x, y, z`), glam last (`x, y, z, w`).
+impl From<aiQuaternion> for Quat {
+    fn from(q: aiQuaternion) -> Self {
+        Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quat> for aiQuaternion {
+    fn from(q: Quat) -> Self {
+        let [x, y, z, w]: [ai_real; 4] = q.into();
+        aiQuaternion { w, x, y, z }
+    }
+}
+
+// glam matrices are column-major, so the conversions transpose.
+impl From<aiMatrix3x3> for Mat3 {
+    fn from(m: aiMatrix3x3) -> Self {
+        Mat3::from_cols_array(&[m.a1, m.b1, m.c1, m.a2, m.b2, m.c2, m.a3, m.b3, m.c3])
+    }
+}
+
+impl From<Mat3> for aiMatrix3x3 {
+    fn from(m: Mat3) -> Self {
+        let [a1, b1, c1, a2, b2, c2, a3, b3, c3] = m.to_cols_array();
+        aiMatrix3x3 { a1, a2, a3, b1, b2, b3, c1, c2, c3 }
+    }
+}
+
+impl From<aiMatrix4x4> for Mat4 {
+    fn from(m: aiMatrix4x4) -> Self {
+        Mat4::from_cols_array(&[
+            m.a1, m.b1, m.c1, m.d1, m.a2, m.b2, m.c2, m.d2, m.a3, m.b3, m.c3, m.d3, m.a4, m.b4,
+            m.c4, m.d4,
+        ])
+    }
+}
+
+impl From<Mat4> for aiMatrix4x4 {
+    fn from(m: Mat4) -> Self {
+        let [a1, b1, c1, d1, a2, b2, c2, d2, a3, b3, c3, d3, a4, b4, c4, d4] = m.to_cols_array();
+        aiMatrix4x4 { a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4 }
+    }
+}
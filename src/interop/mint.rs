@@ -0,0 +1,126 @@
+use mint::{
+    ColumnMatrix3, ColumnMatrix4, Quaternion, RowMatrix3, RowMatrix4, Vector2, Vector3, Vector4,
+};
+
+use crate::{ai_real, aiMatrix3x3, aiMatrix4x4, aiQuaternion, aiVector2D, aiVector3D};
+
+impl From<aiVector2D> for Vector2<ai_real> {
+    fn from(v: aiVector2D) -> Self {
+        Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vector2<ai_real>> for aiVector2D {
+    fn from(v: Vector2<ai_real>) -> Self {
+        aiVector2D { x: v.x, y: v.y }
+    }
+}
+
+impl From<aiVector3D> for Vector3<ai_real> {
+    fn from(v: aiVector3D) -> Self {
+        Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<Vector3<ai_real>> for aiVector3D {
+    fn from(v: Vector3<ai_real>) -> Self {
+        aiVector3D { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+// Assimp stores the scalar part first (`w, x, y, z`), mint keeps it separate.
+impl From<aiQuaternion> for Quaternion<ai_real> {
+    fn from(q: aiQuaternion) -> Self {
+        Quaternion { v: Vector3 { x: q.x, y: q.y, z: q.z }, s: q.w }
+    }
+}
+
+impl From<Quaternion<ai_real>> for aiQuaternion {
+    fn from(q: Quaternion<ai_real>) -> Self {
+        aiQuaternion { w: q.s, x: q.v.x, y: q.v.y, z: q.v.z }
+    }
+}
+
+impl From<aiMatrix3x3> for RowMatrix3<ai_real> {
+    fn from(m: aiMatrix3x3) -> Self {
+        RowMatrix3 {
+            x: Vector3 { x: m.a1, y: m.a2, z: m.a3 },
+            y: Vector3 { x: m.b1, y: m.b2, z: m.b3 },
+            z: Vector3 { x: m.c1, y: m.c2, z: m.c3 },
+        }
+    }
+}
+
+impl From<RowMatrix3<ai_real>> for aiMatrix3x3 {
+    fn from(m: RowMatrix3<ai_real>) -> Self {
+        aiMatrix3x3 {
+            a1: m.x.x,
+            a2: m.x.y,
+            a3: m.x.z,
+            b1: m.y.x,
+            b2: m.y.y,
+            b3: m.y.z,
+            c1: m.z.x,
+            c2: m.z.y,
+            c3: m.z.z,
+        }
+    }
+}
+
+impl From<aiMatrix3x3> for ColumnMatrix3<ai_real> {
+    fn from(m: aiMatrix3x3) -> Self {
+        RowMatrix3::from(m).into()
+    }
+}
+
+impl From<ColumnMatrix3<ai_real>> for aiMatrix3x3 {
+    fn from(m: ColumnMatrix3<ai_real>) -> Self {
+        RowMatrix3::from(m).into()
+    }
+}
+
+impl From<aiMatrix4x4> for RowMatrix4<ai_real> {
+    fn from(m: aiMatrix4x4) -> Self {
+        RowMatrix4 {
+            x: Vector4 { x: m.a1, y: m.a2, z: m.a3, w: m.a4 },
+            y: Vector4 { x: m.b1, y: m.b2, z: m.b3, w: m.b4 },
+            z: Vector4 { x: m.c1, y: m.c2, z: m.c3, w: m.c4 },
+            w: Vector4 { x: m.d1, y: m.d2, z: m.d3, w: m.d4 },
+        }
+    }
+}
+
+impl From<RowMatrix4<ai_real>> for aiMatrix4x4 {
+    fn from(m: RowMatrix4<ai_real>) -> Self {
+        aiMatrix4x4 {
+            a1: m.x.x,
+            a2: m.x.y,
+            a3: m.x.z,
+            a4: m.x.w,
+            b1: m.y.x,
+            b2: m.y.y,
+            b3: m.y.z,
+            b4: m.y.w,
+            c1: m.z.x,
+            c2: m.z.y,
+            c3: m.z.z,
+            c4: m.z.w,
+            d1: m.w.x,
+            d2: m.w.y,
+            d3: m.w.z,
+            d4: m.w.w,
+        }
+    }
+}
+
+impl From<aiMatrix4x4> for ColumnMatrix4<ai_real> {
+    fn from(m: aiMatrix4x4) -> Self {
+        RowMatrix4::from(m).into()
+    }
+}
+
+impl From<ColumnMatrix4<ai_real>> for aiMatrix4x4 {
+    fn from(m: ColumnMatrix4<ai_real>) -> Self {
+        RowMatrix4::from(m).into()
+    }
+}
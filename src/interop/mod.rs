@@ -0,0 +1,13 @@
+//! Conversions and trait impls between the assimp value types and other crates, each behind a
+//! feature of the same name.
+//!
+//! Assimp's matrices are row-major: `a1 a2 a3 a4` is the first row.
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,148 @@
+//! The value types are generated, so serde's remote derive describes their fields here and the
+//! impls forward to it. `aiString` is (de)serialized as a plain string.
+
+use alloc::format;
+use alloc::string::String;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde::{Deserialize as DeriveDeserialize, Serialize as DeriveSerialize};
+
+use crate::{
+    ai_real, aiAABB, aiColor3D, aiColor4D, aiMatrix3x3, aiMatrix4x4, aiQuaternion, aiString,
+    aiTexel, aiVector2D, aiVector3D, MAXLEN,
+};
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiVector2D")]
+struct Vector2D {
+    x: ai_real,
+    y: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiVector3D")]
+struct Vector3D {
+    x: ai_real,
+    y: ai_real,
+    z: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiColor3D")]
+struct Color3D {
+    r: ai_real,
+    g: ai_real,
+    b: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiColor4D")]
+struct Color4D {
+    r: ai_real,
+    g: ai_real,
+    b: ai_real,
+    a: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiQuaternion")]
+struct Quaternion {
+    w: ai_real,
+    x: ai_real,
+    y: ai_real,
+    z: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiMatrix3x3")]
+struct Matrix3x3 {
+    a1: ai_real,
+    a2: ai_real,
+    a3: ai_real,
+    b1: ai_real,
+    b2: ai_real,
+    b3: ai_real,
+    c1: ai_real,
+    c2: ai_real,
+    c3: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiMatrix4x4")]
+struct Matrix4x4 {
+    a1: ai_real,
+    a2: ai_real,
+    a3: ai_real,
+    a4: ai_real,
+    b1: ai_real,
+    b2: ai_real,
+    b3: ai_real,
+    b4: ai_real,
+    c1: ai_real,
+    c2: ai_real,
+    c3: ai_real,
+    c4: ai_real,
+    d1: ai_real,
+    d2: ai_real,
+    d3: ai_real,
+    d4: ai_real,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiAABB")]
+struct Aabb {
+    mMin: aiVector3D,
+    mMax: aiVector3D,
+}
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+#[serde(remote = "aiTexel")]
+struct Texel {
+    b: u8,
+    g: u8,
+    r: u8,
+    a: u8,
+}
+
+macro_rules! forward {
+    ($($ty:ident => $remote:ident),* $(,)?) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $remote::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $remote::deserialize(deserializer)
+            }
+        }
+    )*};
+}
+
+forward! {
+    aiVector2D => Vector2D,
+    aiVector3D => Vector3D,
+    aiColor3D => Color3D,
+    aiColor4D => Color4D,
+    aiQuaternion => Quaternion,
+    aiMatrix3x3 => Matrix3x3,
+    aiMatrix4x4 => Matrix4x4,
+    aiAABB => Aabb,
+    aiTexel => Texel,
+}
+
+impl Serialize for aiString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(self.valid_bytes()))
+    }
+}
+
+impl<'de> Deserialize<'de> for aiString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        aiString::with_bytes(string.as_bytes()).ok_or_else(|| {
+            de::Error::invalid_length(string.len(), &format!("at most {} bytes", MAXLEN - 1).as_str())
+        })
+    }
+}
@@ -1,8 +1,124 @@
+#![cfg_attr(feature = "no-std", no_std)]
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(improper_ctypes)]
+// The generated `Assimp` methods have no docs at all.
+#![cfg_attr(feature = "dynamic-loading", allow(clippy::missing_safety_doc))]
 
+// Everything outside of `helpers` only uses `core` and `alloc`, so that it works with `no-std`.
+extern crate alloc;
+#[cfg(feature = "libz-sys")]
 extern crate libz_sys;
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+// `anim`, `material`, `mesh`, `postprocess`, `scene` and `texture`, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/modules.rs"));
+
+/// How the build script configured assimp, for diagnostics and bug reports.
+pub mod build_info {
+    include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+}
+
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(not(feature = "double-precision"))]
+type Real = f32;
+#[cfg(feature = "double-precision")]
+type Real = f64;
+
+/// The floating point type of assimp's vectors, colors, quaternions and matrices.
+///
+/// `f32` unless the `double-precision` feature is enabled, where it's `f64`. Code using the
+/// value types should use this instead of naming either, so that it works with both.
+pub type ai_real = Real;
+
+/// Assimp's fixed-capacity string type.
+///
+/// Written by hand instead of being generated so that its `Debug` impl only prints the first
+/// `length` bytes, rather than the whole buffer including whatever follows the terminator.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+pub struct aiString {
+    pub length: u32,
+    pub data: [::core::ffi::c_char; MAXLEN as usize],
+}
+
+impl aiString {
+    /// Copies `bytes` into a new string, or `None` if there's no room left for the terminator.
+    #[cfg(any(feature = "helpers", feature = "serde"))]
+    pub(crate) fn with_bytes(bytes: &[u8]) -> Option<aiString> {
+        if bytes.len() >= MAXLEN as usize {
+            return None;
+        }
+
+        let mut string = aiString { length: bytes.len() as u32, data: [0; MAXLEN as usize] };
+        for (dst, &src) in string.data.iter_mut().zip(bytes) {
+            *dst = src as ::core::ffi::c_char;
+        }
+
+        Some(string)
+    }
+
+    pub(crate) fn valid_bytes(&self) -> &[u8] {
+        let len = (self.length as usize).min(self.data.len());
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const u8, len) }
+    }
+}
+
+impl fmt::Debug for aiString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&String::from_utf8_lossy(self.valid_bytes()), f)
+    }
+}
+
+#[cfg(feature = "dynamic-loading")]
+impl Assimp {
+    /// Opens the assimp shared library at `path` and looks up all of its functions.
+    ///
+    /// Functions missing from the library are reported when they're first called rather than
+    /// here, so a library older than the bindings can still be used for what it does provide.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialisation code, and `path` must be an assimp whose ABI
+    /// matches these bindings.
+    pub unsafe fn load(path: &str) -> Result<Assimp, libloading::Error> {
+        Assimp::new(path)
+    }
+}
+
+mod interop;
+
+mod material_keys;
+pub use material_keys::*;
+
+// The functions are members of `Assimp` when loading dynamically.
+#[cfg(not(feature = "dynamic-loading"))]
+mod version;
+#[cfg(not(feature = "dynamic-loading"))]
+pub use version::{legal_string, version};
+
+#[cfg(feature = "bitflags")]
+mod flags;
+#[cfg(feature = "bitflags")]
+pub use flags::{PostProcessSteps, TextureFlags};
+
+// The helpers call the linked functions directly.
+#[cfg(all(feature = "helpers", feature = "dynamic-loading"))]
+compile_error!("The `helpers` and `dynamic-loading` features can't be used together");
+
+// They need I/O, threads and `CString`.
+#[cfg(all(feature = "helpers", feature = "no-std"))]
+compile_error!("The `helpers` feature needs std and can't be used with `no-std`");
+
+// libloading and bindgen's generated loader code need std.
+#[cfg(all(feature = "dynamic-loading", feature = "no-std"))]
+compile_error!("The `dynamic-loading` feature needs std and can't be used with `no-std`");
+
+#[cfg(feature = "helpers")]
+mod helpers;
+#[cfg(feature = "helpers")]
+pub use helpers::*;
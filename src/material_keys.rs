@@ -0,0 +1,132 @@
+//! The `AI_MATKEY_*` material keys, which are C macros and so aren't generated by bindgen.
+//! Those that take a texture type and index are `const fn`s.
+
+use core::ffi::{c_uint, CStr};
+
+use crate::aiTextureType;
+
+/// A material property key, the `(key, type, index)` triple an `AI_MATKEY_*` macro expands to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaterialKey {
+    /// The property name, for `pKey`.
+    pub key: &'static CStr,
+    /// The texture type the property belongs to, for `type`. Zero for non-texture properties.
+    pub semantic: c_uint,
+    /// The texture index within `semantic`, for `index`. Zero for non-texture properties.
+    pub index: c_uint,
+}
+
+macro_rules! material_keys {
+    ($($(#[$attr:meta])* $name:ident = $key:literal;)*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: MaterialKey = MaterialKey {
+                key: unsafe { CStr::from_bytes_with_nul_unchecked(concat!($key, "\0").as_bytes()) },
+                semantic: 0,
+                index: 0,
+            };
+        )*
+    };
+}
+
+material_keys! {
+    /// The material's name, an `aiString`.
+    AI_MATKEY_NAME = "?mat.name";
+    /// Whether backface culling must be disabled, an integer.
+    AI_MATKEY_TWOSIDED = "$mat.twosided";
+    /// The shading model, an `aiShadingMode` as an integer.
+    AI_MATKEY_SHADING_MODEL = "$mat.shadingm";
+    /// Whether to render as wireframe, an integer.
+    AI_MATKEY_ENABLE_WIREFRAME = "$mat.wireframe";
+    /// How to blend with the framebuffer, an `aiBlendMode` as an integer.
+    AI_MATKEY_BLEND_FUNC = "$mat.blend";
+    /// The opacity, a float from 0 (transparent) to 1.
+    AI_MATKEY_OPACITY = "$mat.opacity";
+    /// The transparency factor, a float (the complement of the opacity in some formats).
+    AI_MATKEY_TRANSPARENCYFACTOR = "$mat.transparencyfactor";
+    /// The scale of the bump map, a float.
+    AI_MATKEY_BUMPSCALING = "$mat.bumpscaling";
+    /// The specular exponent, a float.
+    AI_MATKEY_SHININESS = "$mat.shininess";
+    /// The reflectivity, a float.
+    AI_MATKEY_REFLECTIVITY = "$mat.reflectivity";
+    /// The scale of the specular color, a float.
+    AI_MATKEY_SHININESS_STRENGTH = "$mat.shinpercent";
+    /// The index of refraction, a float.
+    AI_MATKEY_REFRACTI = "$mat.refracti";
+    /// The diffuse color, an `aiColor4D`.
+    AI_MATKEY_COLOR_DIFFUSE = "$clr.diffuse";
+    /// The ambient color, an `aiColor4D`.
+    AI_MATKEY_COLOR_AMBIENT = "$clr.ambient";
+    /// The specular color, an `aiColor4D`.
+    AI_MATKEY_COLOR_SPECULAR = "$clr.specular";
+    /// The emissive color, an `aiColor4D`.
+    AI_MATKEY_COLOR_EMISSIVE = "$clr.emissive";
+    /// The transparent color, an `aiColor4D`.
+    AI_MATKEY_COLOR_TRANSPARENT = "$clr.transparent";
+    /// The reflective color, an `aiColor4D`.
+    AI_MATKEY_COLOR_REFLECTIVE = "$clr.reflective";
+    /// The PBR base color, an `aiColor4D`. Assimp 5.1 and later.
+    AI_MATKEY_BASE_COLOR = "$clr.base";
+    /// The PBR metallic factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_METALLIC_FACTOR = "$mat.metallicFactor";
+    /// The PBR roughness factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_ROUGHNESS_FACTOR = "$mat.roughnessFactor";
+    /// The scale of the emissive color, a float. Assimp 5.1 and later.
+    AI_MATKEY_EMISSIVE_INTENSITY = "$mat.emissiveIntensity";
+    /// The PBR specular factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_SPECULAR_FACTOR = "$mat.specularFactor";
+    /// The PBR glossiness factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_GLOSSINESS_FACTOR = "$mat.glossinessFactor";
+    /// The sheen color, an `aiColor4D`. Assimp 5.1 and later.
+    AI_MATKEY_SHEEN_COLOR_FACTOR = "$clr.sheen.factor";
+    /// The sheen roughness, a float. Assimp 5.1 and later.
+    AI_MATKEY_SHEEN_ROUGHNESS_FACTOR = "$mat.sheen.roughness.factor";
+    /// The clearcoat factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_CLEARCOAT_FACTOR = "$mat.clearcoat.factor";
+    /// The clearcoat roughness, a float. Assimp 5.1 and later.
+    AI_MATKEY_CLEARCOAT_ROUGHNESS_FACTOR = "$mat.clearcoat.roughness.factor";
+    /// The transmission factor, a float. Assimp 5.1 and later.
+    AI_MATKEY_TRANSMISSION_FACTOR = "$mat.transmission.factor";
+    /// The path of a global background image, an `aiString`.
+    AI_MATKEY_GLOBAL_BACKGROUND_IMAGE = "?bg.global";
+}
+
+macro_rules! texture_keys {
+    ($($(#[$attr:meta])* $name:ident = $key:literal;)*) => {
+        $(
+            $(#[$attr])*
+            pub const fn $name(semantic: aiTextureType, index: c_uint) -> MaterialKey {
+                MaterialKey {
+                    key: unsafe { CStr::from_bytes_with_nul_unchecked(concat!($key, "\0").as_bytes()) },
+                    semantic: semantic as c_uint,
+                    index,
+                }
+            }
+        )*
+    };
+}
+
+// The macros taking a texture type and index, e.g. `AI_MATKEY_TEXTURE(aiTextureType_DIFFUSE, 0)`.
+texture_keys! {
+    /// The path of a texture, an `aiString`.
+    AI_MATKEY_TEXTURE = "$tex.file";
+    /// The UV channel a texture uses, an integer.
+    AI_MATKEY_UVWSRC = "$tex.uvwsrc";
+    /// How a texture is combined with the previous one, an `aiTextureOp` as an integer.
+    AI_MATKEY_TEXOP = "$tex.op";
+    /// How a texture is mapped, an `aiTextureMapping` as an integer.
+    AI_MATKEY_MAPPING = "$tex.mapping";
+    /// The blend factor of a texture, a float.
+    AI_MATKEY_TEXBLEND = "$tex.blend";
+    /// The wrapping of a texture along U, an `aiTextureMapMode` as an integer.
+    AI_MATKEY_MAPPINGMODE_U = "$tex.mapmodeu";
+    /// The wrapping of a texture along V, an `aiTextureMapMode` as an integer.
+    AI_MATKEY_MAPPINGMODE_V = "$tex.mapmodev";
+    /// The mapping axis of a non-UV texture, an `aiVector3D`.
+    AI_MATKEY_TEXMAP_AXIS = "$tex.mapaxis";
+    /// The UV transform of a texture, an `aiUVTransform`.
+    AI_MATKEY_UVTRANSFORM = "$tex.uvtrafo";
+    /// The `aiTextureFlags` of a texture, an integer.
+    AI_MATKEY_TEXFLAGS = "$tex.flags";
+}
@@ -0,0 +1,19 @@
+// The sizes of assimp's structs as the C compiler sees them with the headers that were found,
+// which `tests/layout.rs` compares against the bindings to catch them disagreeing with the
+// linked assimp (e.g. pregenerated bindings with a different system assimp).
+
+#include <assimp/scene.h>
+
+#include <stddef.h>
+
+#define SIZE_OF(name, type) \
+    size_t assimp_sys_size_of_##name(void) { return sizeof(type); }
+
+SIZE_OF(scene, struct aiScene)
+SIZE_OF(node, struct aiNode)
+SIZE_OF(mesh, struct aiMesh)
+SIZE_OF(material, struct aiMaterial)
+SIZE_OF(texture, struct aiTexture)
+SIZE_OF(string, struct aiString)
+SIZE_OF(vector3d, struct aiVector3D)
+SIZE_OF(matrix4x4, struct aiMatrix4x4)
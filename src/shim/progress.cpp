@@ -0,0 +1,54 @@
+// Progress reporting only exists in assimp's C++ API, so `import_file_with_progress` goes
+// through this instead of `aiImportFile`.
+
+#include <assimp/Importer.hpp>
+#include <assimp/ProgressHandler.hpp>
+#include <assimp/scene.h>
+
+#include <cstddef>
+#include <cstring>
+
+typedef bool (*assimp_sys_progress_callback)(void *user_data, float percentage);
+
+namespace {
+
+class CallbackProgressHandler : public Assimp::ProgressHandler {
+public:
+    CallbackProgressHandler(assimp_sys_progress_callback callback, void *user_data)
+        : callback_(callback), user_data_(user_data) {}
+
+    bool Update(float percentage) override {
+        return callback_(user_data_, percentage);
+    }
+
+private:
+    assimp_sys_progress_callback callback_;
+    void *user_data_;
+};
+
+} // namespace
+
+// Returns a scene to be released with `aiReleaseImport`, or null with the reason written to
+// `error` (truncated to `error_len` bytes, including the terminator).
+extern "C" const aiScene *assimp_sys_import_with_progress(
+    const char *path,
+    unsigned int flags,
+    assimp_sys_progress_callback callback,
+    void *user_data,
+    char *error,
+    size_t error_len) {
+    Assimp::Importer importer;
+    // The importer owns and deletes its progress handler.
+    importer.SetProgressHandler(new CallbackProgressHandler(callback, user_data));
+
+    if (!importer.ReadFile(path, flags)) {
+        if (error_len > 0) {
+            std::strncpy(error, importer.GetErrorString(), error_len - 1);
+            error[error_len - 1] = '\0';
+        }
+        return nullptr;
+    }
+
+    // Detached scenes have no importer attached, which `aiReleaseImport` frees with `delete`.
+    return importer.GetOrphanedScene();
+}
@@ -0,0 +1,25 @@
+use core::ffi::CStr;
+
+use crate::{aiGetLegalString, aiGetVersionMajor, aiGetVersionMinor, aiGetVersionRevision};
+
+/// The linked assimp's `(major, minor, revision)`.
+///
+/// Unlike `DEP_ASSIMP_VERSION` this is queried at runtime, so it's also correct when the library
+/// found at runtime isn't the one the crate was built against. The revision is assimp's git
+/// commit hash as an integer rather than a patch version.
+pub fn version() -> (u32, u32, u32) {
+    unsafe { (aiGetVersionMajor(), aiGetVersionMinor(), aiGetVersionRevision()) }
+}
+
+/// Assimp's copyright and license notice.
+///
+/// Empty if the library returns null, or a notice that isn't valid UTF-8.
+pub fn legal_string() -> &'static str {
+    let legal = unsafe { aiGetLegalString() };
+    if legal.is_null() {
+        return "";
+    }
+
+    // Points to a string literal inside the library, which lives as long as the process.
+    unsafe { CStr::from_ptr(legal) }.to_str().unwrap_or("")
+}
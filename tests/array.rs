@@ -0,0 +1,37 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::{ai_real, aiColor4D, aiMatrix4x4, aiVector2D, aiVector3D};
+
+#[test]
+fn vectors_round_trip() {
+    let v = aiVector3D { x: 1.0, y: 2.0, z: 3.0 };
+    assert_eq!(<[ai_real; 3]>::from(v), [1.0, 2.0, 3.0]);
+    assert_eq!(aiVector3D::from([1.0, 2.0, 3.0]), v);
+
+    let v = aiVector2D { x: 1.0, y: 2.0 };
+    assert_eq!(<[ai_real; 2]>::from(v), [1.0, 2.0]);
+}
+
+#[test]
+fn colors_keep_rgba_order() {
+    let c = aiColor4D { r: 0.1, g: 0.2, b: 0.3, a: 0.4 };
+    assert_eq!(<[ai_real; 4]>::from(c), [0.1, 0.2, 0.3, 0.4]);
+    assert_eq!(aiColor4D::from([0.1, 0.2, 0.3, 0.4]), c);
+}
+
+#[test]
+fn matrices_are_row_major() {
+    let flat: [ai_real; 16] = [
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ];
+    let m = aiMatrix4x4::from(flat);
+    // The translation of a transform is in the last column.
+    assert_eq!((m.a4, m.b4, m.c4), (4.0, 8.0, 12.0));
+    assert_eq!(<[ai_real; 16]>::from(m), flat);
+
+    let rows = <[[ai_real; 4]; 4]>::from(m);
+    assert_eq!(rows[1], [5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(aiMatrix4x4::from(rows), m);
+}
@@ -0,0 +1,32 @@
+extern crate assimp_sys;
+
+use assimp_sys::build_info::*;
+
+#[test]
+fn backend() {
+    let backends = [
+        "bundled",
+        "assimp-dir",
+        "pkg-config",
+        "homebrew",
+        "vcpkg",
+        "dynamic-loading",
+    ];
+    assert!(backends.contains(&BACKEND), "unexpected backend {}", BACKEND);
+    assert_eq!(BACKEND == "dynamic-loading", cfg!(feature = "dynamic-loading"));
+}
+
+#[test]
+fn precision() {
+    assert_eq!(DOUBLE_PRECISION, cfg!(feature = "double-precision"));
+    assert_eq!(DOUBLE_PRECISION, std::mem::size_of::<assimp_sys::ai_real>() == 8);
+}
+
+#[test]
+fn importers() {
+    if BACKEND == "bundled" {
+        assert!(IMPORTERS.contains(&"obj") || !cfg!(feature = "importer-obj"));
+    } else {
+        assert!(IMPORTERS.is_empty());
+    }
+}
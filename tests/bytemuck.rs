@@ -0,0 +1,28 @@
+#![cfg(feature = "bytemuck")]
+
+extern crate assimp_sys;
+extern crate bytemuck;
+
+use assimp_sys::*;
+
+#[test]
+fn cast_vertices() {
+    let vertices = [
+        aiVector3D { x: 1.0, y: 2.0, z: 3.0 },
+        aiVector3D { x: 4.0, y: 5.0, z: 6.0 },
+    ];
+    let floats: &[ai_real] = bytemuck::cast_slice(&vertices);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn cast_texels() {
+    let texels = [aiTexel { b: 1, g: 2, r: 3, a: 4 }];
+    assert_eq!(bytemuck::cast_slice::<_, u8>(&texels), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn zeroed_matrix() {
+    let m: aiMatrix4x4 = bytemuck::Zeroable::zeroed();
+    assert_eq!(bytemuck::cast::<_, [ai_real; 16]>(m), [0.0; 16]);
+}
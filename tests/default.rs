@@ -0,0 +1,18 @@
+extern crate assimp_sys;
+
+use assimp_sys::*;
+
+#[test]
+fn values_default_to_zero() {
+    assert_eq!(aiVector3D::default(), aiVector3D { x: 0.0, y: 0.0, z: 0.0 });
+    assert_eq!(aiColor4D::default(), aiColor4D { r: 0.0, g: 0.0, b: 0.0, a: 0.0 });
+    assert_eq!(aiTexel::default(), aiTexel { b: 0, g: 0, r: 0, a: 0 });
+}
+
+#[test]
+fn default_matches_zeroed() {
+    let zeroed: aiMatrix4x4 = unsafe { std::mem::zeroed() };
+    assert_eq!(aiMatrix4x4::default(), zeroed);
+    let zeroed: aiQuaternion = unsafe { std::mem::zeroed() };
+    assert_eq!(aiQuaternion::default(), zeroed);
+}
@@ -0,0 +1,36 @@
+#![cfg(all(feature = "helpers", not(feature = "no-exporters")))]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::fs;
+
+fn triangle() -> Scene {
+    import_from_memory(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n", 0, Some("obj")).unwrap()
+}
+
+#[test]
+fn export_obj() {
+    let path = std::env::temp_dir().join("assimp-sys-export-triangle.obj");
+    export_scene(&triangle(), "obj", path.to_str().unwrap(), 0).unwrap();
+
+    let exported = import_file(path.to_str().unwrap(), 0).unwrap();
+    assert_eq!(exported.as_ref().mNumMeshes, 1);
+
+    fs::remove_file(&path).unwrap();
+    let _ = fs::remove_file(path.with_extension("mtl"));
+}
+
+#[test]
+fn unknown_format() {
+    let error = export_scene(&triangle(), "not-a-format", "out.bin", 0).unwrap_err();
+    assert!(error.contains("not-a-format"));
+}
+
+#[test]
+fn formats() {
+    let formats = export_formats();
+    let obj = formats.iter().find(|format| format.id == "obj").unwrap();
+    assert_eq!(obj.file_extension, "obj");
+    assert!(!obj.description.is_empty());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "glam")]
+
+extern crate assimp_sys;
+extern crate glam;
+
+use assimp_sys::*;
+
+#[cfg(feature = "double-precision")]
+use glam::{DMat4 as Mat4, DQuat as Quat, DVec3 as Vec3, DVec4 as Vec4};
+#[cfg(not(feature = "double-precision"))]
+use glam::{Mat4, Quat, Vec3, Vec4};
+
+fn sequential_matrix() -> aiMatrix4x4 {
+    aiMatrix4x4 {
+        a1: 1.0,
+        a2: 2.0,
+        a3: 3.0,
+        a4: 4.0,
+        b1: 5.0,
+        b2: 6.0,
+        b3: 7.0,
+        b4: 8.0,
+        c1: 9.0,
+        c2: 10.0,
+        c3: 11.0,
+        c4: 12.0,
+        d1: 13.0,
+        d2: 14.0,
+        d3: 15.0,
+        d4: 16.0,
+    }
+}
+
+#[test]
+fn matrix_is_transposed() {
+    let m: Mat4 = sequential_matrix().into();
+    assert_eq!(m.x_axis, Vec4::new(1.0, 5.0, 9.0, 13.0));
+    assert_eq!(m.w_axis, Vec4::new(4.0, 8.0, 12.0, 16.0));
+    assert_eq!(aiMatrix4x4::from(m), sequential_matrix());
+}
+
+#[test]
+fn translation_stays_in_last_column() {
+    let translation = Vec3::new(1.0, 2.0, 3.0);
+    let m: aiMatrix4x4 = Mat4::from_translation(translation).into();
+    assert_eq!((m.a4, m.b4, m.c4), (1.0, 2.0, 3.0));
+    assert_eq!((m.d1, m.d2, m.d3, m.d4), (0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn quaternion_order() {
+    let q = aiQuaternion { w: 1.0, x: 2.0, y: 3.0, z: 4.0 };
+    let converted: Quat = q.into();
+    assert_eq!((converted.x, converted.y, converted.z, converted.w), (2.0, 3.0, 4.0, 1.0));
+    assert_eq!(aiQuaternion::from(converted), q);
+}
@@ -0,0 +1,116 @@
+// Always built, so that linking and the ABI of the basic import path get exercised whatever the
+// features. `helpers` adds the tests of its own import functions.
+#![cfg(not(feature = "dynamic-loading"))]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::ffi::CString;
+
+const QUAD_OBJ: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/quad.obj");
+
+// Imports `tests/assets/quad.obj` through the raw API and hands the scene to `check` before
+// releasing it.
+fn with_quad(flags: u32, check: impl FnOnce(&aiScene, &aiMesh)) {
+    let path = CString::new(QUAD_OBJ).unwrap();
+    let scene = unsafe { aiImportFile(path.as_ptr(), flags) };
+    assert!(!scene.is_null(), "importing {} failed", QUAD_OBJ);
+
+    unsafe {
+        assert_eq!((*scene).mNumMeshes, 1);
+        check(&*scene, &**(*scene).mMeshes);
+        aiReleaseImport(scene);
+    }
+}
+
+#[test]
+fn import_sample_model() {
+    with_quad(0, |scene, mesh| {
+        assert!(!scene.mRootNode.is_null());
+        assert_eq!(mesh.mNumVertices, 4);
+        assert_eq!(mesh.mNumFaces, 1);
+        assert!(!mesh.mNormals.is_null());
+    });
+}
+
+#[test]
+fn import_sample_model_triangulated() {
+    with_quad(aiPostProcessSteps_aiProcess_Triangulate, |_, mesh| {
+        assert_eq!(mesh.mNumFaces, 2);
+    });
+}
+
+#[cfg(feature = "helpers")]
+mod helpers {
+    use super::QUAD_OBJ;
+    use assimp_sys::*;
+    use std::fs;
+
+    const TRIANGLE_OBJ: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+
+    #[test]
+    fn import_obj_file() {
+        let path = std::env::temp_dir().join("assimp-sys-import-triangle.obj");
+        fs::write(&path, TRIANGLE_OBJ).unwrap();
+
+        let scene = import_file(path.to_str().unwrap(), 0).unwrap();
+        assert_eq!(scene.as_ref().mNumMeshes, 1);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn import_obj_from_memory() {
+        let scene = import_from_memory(TRIANGLE_OBJ.as_bytes(), 0, Some("obj")).unwrap();
+        assert_eq!(scene.as_ref().mNumMeshes, 1);
+    }
+
+    #[test]
+    fn import_obj_from_reader() {
+        let scene = import_reader(TRIANGLE_OBJ.as_bytes(), 0, Some("obj"), 1024).unwrap();
+        assert_eq!(scene.as_ref().mNumMeshes, 1);
+
+        let file = fs::File::open(QUAD_OBJ).unwrap();
+        let scene = import_reader(file, 0, Some("obj"), 1024).unwrap();
+        assert_eq!(scene.as_ref().mNumMeshes, 1);
+    }
+
+    #[test]
+    fn reader_limit() {
+        let len = TRIANGLE_OBJ.len() as u64;
+        assert!(import_reader(TRIANGLE_OBJ.as_bytes(), 0, Some("obj"), len).is_ok());
+        let error = import_reader(TRIANGLE_OBJ.as_bytes(), 0, Some("obj"), len - 1).unwrap_err();
+        assert!(error.contains("limit"));
+    }
+
+    #[test]
+    fn unrecognised_memory() {
+        assert!(import_from_memory(b"not a model", 0, None).is_err());
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = import_file("does/not/exist.obj", 0).unwrap_err();
+        assert!(!error.is_empty());
+    }
+
+    #[test]
+    fn nul_in_strings() {
+        assert!(import_file("a\0b.obj", 0).is_err());
+        assert!(import_from_memory(TRIANGLE_OBJ.as_bytes(), 0, Some("o\0bj")).is_err());
+    }
+
+    #[test]
+    fn extensions() {
+        let extensions = supported_import_extensions();
+        assert!(extensions.iter().any(|extension| extension == "obj"));
+        assert!(extensions.iter().all(|extension| !extension.is_empty() && !extension.contains('.')));
+    }
+
+    #[test]
+    fn import_sample_model() {
+        let scene = import_file(QUAD_OBJ, 0).unwrap();
+        let mesh = scene.meshes().next().unwrap();
+        assert_eq!(mesh.vertices().len(), 4);
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[derive(Default)]
+struct MemoryFs {
+    files: HashMap<String, Vec<u8>>,
+    opened: RefCell<Vec<String>>,
+}
+
+impl AssimpIo for MemoryFs {
+    fn open(&self, path: &str) -> Option<Box<dyn AssimpFile>> {
+        self.opened.borrow_mut().push(path.to_owned());
+        let data = self.files.get(path)?.clone();
+        Some(Box::new(Cursor::new(data)))
+    }
+}
+
+#[test]
+fn import_through_io() {
+    let mut fs = MemoryFs::default();
+    let obj = "mtllib triangle.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl red\nf 1 2 3\n";
+    let mtl = "newmtl red\nKd 1 0 0\n";
+    fs.files.insert("triangle.obj".to_owned(), obj.as_bytes().to_vec());
+    fs.files.insert("triangle.mtl".to_owned(), mtl.as_bytes().to_vec());
+
+    let scene = import_file_with_io("triangle.obj", 0, &fs).unwrap();
+    assert_eq!(scene.as_ref().mNumMeshes, 1);
+    assert!(fs.opened.borrow().iter().any(|path| path.ends_with("triangle.mtl")));
+}
+
+#[test]
+fn missing_file_through_io() {
+    let fs = MemoryFs::default();
+    assert!(import_file_with_io("missing.obj", 0, &fs).is_err());
+}
@@ -0,0 +1,38 @@
+#![cfg(not(feature = "dynamic-loading"))]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::mem::size_of;
+
+// From `src/shim/layout.c`, compiled against the same headers as the linked assimp.
+extern "C" {
+    fn assimp_sys_size_of_scene() -> usize;
+    fn assimp_sys_size_of_node() -> usize;
+    fn assimp_sys_size_of_mesh() -> usize;
+    fn assimp_sys_size_of_material() -> usize;
+    fn assimp_sys_size_of_texture() -> usize;
+    fn assimp_sys_size_of_string() -> usize;
+    fn assimp_sys_size_of_vector3d() -> usize;
+    fn assimp_sys_size_of_matrix4x4() -> usize;
+}
+
+#[test]
+fn struct_sizes_match_headers() {
+    unsafe {
+        assert_eq!(size_of::<aiScene>(), assimp_sys_size_of_scene());
+        assert_eq!(size_of::<aiNode>(), assimp_sys_size_of_node());
+        assert_eq!(size_of::<aiMesh>(), assimp_sys_size_of_mesh());
+        assert_eq!(size_of::<aiMaterial>(), assimp_sys_size_of_material());
+        assert_eq!(size_of::<aiTexture>(), assimp_sys_size_of_texture());
+        assert_eq!(size_of::<aiString>(), assimp_sys_size_of_string());
+        assert_eq!(size_of::<aiVector3D>(), assimp_sys_size_of_vector3d());
+        assert_eq!(size_of::<aiMatrix4x4>(), assimp_sys_size_of_matrix4x4());
+    }
+}
+
+#[test]
+fn precision_matches_library() {
+    let double = unsafe { aiGetCompileFlags() } & ASSIMP_CFLAGS_DOUBLE_SUPPORT != 0;
+    assert_eq!(double, cfg!(feature = "double-precision"));
+}
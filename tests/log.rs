@@ -0,0 +1,22 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn messages_while_importing() {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let stream = {
+        let messages = messages.clone();
+        attach_log(LogLevel::Info, move |message| messages.lock().unwrap().push(message.to_owned()))
+    };
+
+    let _ = import_file("does/not/exist.obj", 0);
+    drop(stream);
+
+    let messages = messages.lock().unwrap();
+    assert!(!messages.is_empty());
+    assert!(messages.iter().all(|message| !message.starts_with("Info,") && !message.ends_with('\n')));
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+
+#[test]
+fn missing_properties() {
+    let material: aiMaterial = unsafe { std::mem::zeroed() };
+    assert_eq!(material.color(AI_MATKEY_COLOR_DIFFUSE), None);
+    assert_eq!(material.float(AI_MATKEY_OPACITY), None);
+    assert_eq!(material.string(AI_MATKEY_NAME), None);
+    assert_eq!(material.texture(aiTextureType::aiTextureType_DIFFUSE, 0), None);
+}
+
+#[test]
+fn no_textures() {
+    let material: aiMaterial = unsafe { std::mem::zeroed() };
+    for &texture_type in &[aiTextureType::aiTextureType_BASE_COLOR, aiTextureType::aiTextureType_METALNESS] {
+        assert_eq!(material.texture_count(texture_type), 0);
+        assert_eq!(material.textures(texture_type).count(), 0);
+    }
+}
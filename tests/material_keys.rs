@@ -0,0 +1,18 @@
+extern crate assimp_sys;
+
+use assimp_sys::*;
+
+#[test]
+fn keys_match_the_macros() {
+    assert_eq!(AI_MATKEY_COLOR_DIFFUSE.key.to_bytes(), b"$clr.diffuse");
+    assert_eq!((AI_MATKEY_COLOR_DIFFUSE.semantic, AI_MATKEY_COLOR_DIFFUSE.index), (0, 0));
+    assert_eq!(AI_MATKEY_NAME.key.to_bytes(), b"?mat.name");
+}
+
+#[test]
+fn texture_keys_carry_type_and_index() {
+    let key = AI_MATKEY_TEXTURE(aiTextureType::aiTextureType_NORMALS, 2);
+    assert_eq!(key.key.to_bytes(), b"$tex.file");
+    assert_eq!(key.semantic, aiTextureType::aiTextureType_NORMALS as u32);
+    assert_eq!(key.index, 2);
+}
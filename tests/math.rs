@@ -0,0 +1,168 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::{ai_real, aiColor4D, aiMatrix4x4, aiQuaternion, aiVector3D};
+use std::f64::consts::PI;
+
+fn vec3(x: ai_real, y: ai_real, z: ai_real) -> aiVector3D {
+    aiVector3D { x, y, z }
+}
+
+fn assert_close(a: ai_real, b: ai_real) {
+    assert!((a - b).abs() < 1e-5, "{} != {}", a, b);
+}
+
+fn assert_close_quat(a: aiQuaternion, b: aiQuaternion) {
+    for (a, b) in [(a.w, b.w), (a.x, b.x), (a.y, b.y), (a.z, b.z)] {
+        assert_close(a, b);
+    }
+}
+
+#[test]
+fn cross_is_right_handed() {
+    let (x, y, z) = (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(z), x);
+    assert_eq!(z.cross(x), y);
+    assert_eq!(y.cross(x), -z);
+}
+
+#[test]
+fn dot_and_length() {
+    assert_eq!(vec3(1.0, 2.0, 3.0).dot(vec3(4.0, -5.0, 6.0)), 12.0);
+    assert_eq!(vec3(2.0, 3.0, 6.0).length(), 7.0);
+}
+
+#[test]
+fn vector_ops() {
+    let (a, b) = (vec3(1.0, 2.0, 3.0), vec3(0.5, -1.0, 2.0));
+    assert_eq!(a + b, vec3(1.5, 1.0, 5.0));
+    assert_eq!(a - b, vec3(0.5, 3.0, 1.0));
+    assert_eq!(a * 2.0, vec3(2.0, 4.0, 6.0));
+    assert_eq!(-a, vec3(-1.0, -2.0, -3.0));
+}
+
+#[test]
+fn color_ops_are_per_channel() {
+    let a = aiColor4D { r: 0.5, g: 0.25, b: 1.0, a: 1.0 };
+    let b = aiColor4D { r: 0.5, g: 0.5, b: 0.0, a: 0.5 };
+    assert_eq!(a + b, aiColor4D { r: 1.0, g: 0.75, b: 1.0, a: 1.5 });
+    assert_eq!(a - b, aiColor4D { r: 0.0, g: -0.25, b: 1.0, a: 0.5 });
+    assert_eq!(a * b, aiColor4D { r: 0.25, g: 0.125, b: 0.0, a: 0.5 });
+    assert_eq!(a * 2.0, aiColor4D { r: 1.0, g: 0.5, b: 2.0, a: 2.0 });
+}
+
+#[test]
+fn axis_angle_round_trip() {
+    let pi = PI as ai_real;
+    for &(axis, angle) in &[
+        (vec3(0.0, 0.0, 1.0), pi / 2.0),
+        (vec3(1.0, 2.0, -2.0), 0.3),
+        (vec3(-1.0, 0.0, 0.0), 1.5 * pi),
+    ] {
+        let q = aiQuaternion::from_axis_angle(axis, angle);
+        let length = (q.w * q.w + q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        assert_close(length, 1.0);
+
+        let (back_axis, back_angle) = q.to_axis_angle();
+        let axis = axis * (1.0 / axis.length());
+        assert_close(back_angle, angle);
+        assert_close(back_axis.x, axis.x);
+        assert_close(back_axis.y, axis.y);
+        assert_close(back_axis.z, axis.z);
+    }
+
+    let (axis, angle) = aiQuaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 }.to_axis_angle();
+    assert_eq!((axis, angle), (vec3(1.0, 0.0, 0.0), 0.0));
+}
+
+#[test]
+fn hamilton_product_composes_rotations() {
+    let z = vec3(0.0, 0.0, 1.0);
+    let q = aiQuaternion::from_axis_angle(z, 0.25) * aiQuaternion::from_axis_angle(z, 0.5);
+    assert_close_quat(q, aiQuaternion::from_axis_angle(z, 0.75));
+
+    // i * j = k
+    let i = aiQuaternion { w: 0.0, x: 1.0, y: 0.0, z: 0.0 };
+    let j = aiQuaternion { w: 0.0, x: 0.0, y: 1.0, z: 0.0 };
+    assert_eq!(i * j, aiQuaternion { w: 0.0, x: 0.0, y: 0.0, z: 1.0 });
+    assert_eq!(j * i, aiQuaternion { w: 0.0, x: 0.0, y: 0.0, z: -1.0 });
+}
+
+#[test]
+fn euler_angles() {
+    let (x, y, z) = (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+    let (ax, ay, az) = (aiQuaternion::from_axis_angle(z, 0.5) * aiQuaternion::from_axis_angle(y, -0.25)
+        * aiQuaternion::from_axis_angle(x, 1.0))
+    .to_euler();
+    assert_close(ax, 1.0);
+    assert_close(ay, -0.25);
+    assert_close(az, 0.5);
+
+    // Gimbal lock: x and z rotate around the same axis, so it's all put into z.
+    let pi = PI as ai_real;
+    let locked = aiQuaternion::from_axis_angle(y, pi / 2.0) * aiQuaternion::from_axis_angle(x, 0.5);
+    let (ax, ay, az) = locked.to_euler();
+    assert_close(ax, 0.0);
+    assert_close(ay, pi / 2.0);
+    let rebuilt = aiQuaternion::from_axis_angle(z, az) * aiQuaternion::from_axis_angle(y, ay);
+    assert_close_quat(rebuilt, locked);
+}
+
+#[test]
+fn normalize() {
+    let q = aiQuaternion { w: 2.0, x: 0.0, y: 0.0, z: 0.0 }.normalize();
+    assert_eq!(q, aiQuaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 });
+    let zero = aiQuaternion { w: 0.0, x: 0.0, y: 0.0, z: 0.0 };
+    assert_eq!(zero.normalize(), zero);
+}
+
+fn matrix(rows: [[ai_real; 4]; 4]) -> aiMatrix4x4 {
+    let [[a1, a2, a3, a4], [b1, b2, b3, b4], [c1, c2, c3, c4], [d1, d2, d3, d4]] = rows;
+    aiMatrix4x4 { a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4 }
+}
+
+#[test]
+fn decompose_identity() {
+    let identity = matrix([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+    let (translation, rotation, scale) = identity.decompose();
+    assert_eq!(translation, vec3(0.0, 0.0, 0.0));
+    assert_eq!(rotation, aiQuaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 });
+    assert_eq!(scale, vec3(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn decompose_trs() {
+    // A quarter turn around z, after scaling by (2, 3, 4), then moved by (5, 6, 7).
+    let transform = matrix([[0.0, -3.0, 0.0, 5.0], [2.0, 0.0, 0.0, 6.0], [0.0, 0.0, 4.0, 7.0], [0.0, 0.0, 0.0, 1.0]]);
+    let (translation, rotation, scale) = transform.decompose();
+    assert_eq!(translation, vec3(5.0, 6.0, 7.0));
+    assert_close_quat(rotation, aiQuaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), PI as ai_real / 2.0));
+    assert_eq!(scale, vec3(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn decompose_mirror() {
+    // Mirrored along x, which comes out as a negative scale on every axis and a half turn
+    // around x.
+    let mirror = matrix([[-1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+    let (_, rotation, scale) = mirror.decompose();
+    assert_eq!(scale, vec3(-1.0, -1.0, -1.0));
+    assert_close_quat(rotation, aiQuaternion { w: 0.0, x: 1.0, y: 0.0, z: 0.0 });
+}
+
+#[test]
+fn matrix_product() {
+    let identity = aiMatrix4x4::identity();
+    let scale = matrix([[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+    let translate = matrix([[1.0, 0.0, 0.0, 5.0], [0.0, 1.0, 0.0, 6.0], [0.0, 0.0, 1.0, 7.0], [0.0, 0.0, 0.0, 1.0]]);
+    assert_eq!(identity * scale, scale);
+    assert_eq!(scale * identity, scale);
+
+    // Scaling after translating scales the translation too, the other way around it doesn't.
+    let (translation, _, _) = (scale * translate).decompose();
+    assert_eq!(translation, vec3(10.0, 12.0, 14.0));
+    let (translation, _, _) = (translate * scale).decompose();
+    assert_eq!(translation, vec3(5.0, 6.0, 7.0));
+}
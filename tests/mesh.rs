@@ -0,0 +1,66 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+
+fn vec3(x: ai_real) -> aiVector3D {
+    aiVector3D { x, y: 0.0, z: 0.0 }
+}
+
+#[test]
+fn empty_mesh() {
+    let mesh: aiMesh = unsafe { std::mem::zeroed() };
+    assert!(mesh.vertices().is_empty());
+    assert!(mesh.faces().is_empty());
+    assert!(mesh.normals().is_none());
+    assert!(mesh.uv_channel(0).is_none());
+}
+
+#[test]
+fn attributes_are_bounded_by_vertex_count() {
+    let mut vertices = [vec3(1.0), vec3(2.0), vec3(3.0)];
+    let mut uvs = [vec3(0.5), vec3(0.25), vec3(0.0)];
+
+    let mut mesh: aiMesh = unsafe { std::mem::zeroed() };
+    mesh.mNumVertices = 2;
+    mesh.mVertices = vertices.as_mut_ptr();
+    mesh.mTextureCoords[1] = uvs.as_mut_ptr();
+
+    assert_eq!(mesh.vertices(), &vertices[..2]);
+    assert!(mesh.normals().is_none());
+    assert!(mesh.uv_channel(0).is_none());
+    assert_eq!(mesh.uv_channel(1), Some(&uvs[..2]));
+}
+
+#[test]
+fn out_of_range_uv_channel() {
+    let mesh: aiMesh = unsafe { std::mem::zeroed() };
+    assert!(mesh.uv_channel(AI_MAX_NUMBER_OF_TEXTURECOORDS as usize).is_none());
+}
+
+#[test]
+fn face_indices() {
+    let mut indices = [0, 1, 2];
+    let face = aiFace { mNumIndices: 3, mIndices: indices.as_mut_ptr() };
+    assert_eq!(face.indices(), [0, 1, 2]);
+
+    let degenerate = aiFace { mNumIndices: 0, mIndices: std::ptr::null_mut() };
+    assert!(degenerate.indices().is_empty());
+}
+
+#[test]
+fn all_indices_are_concatenated() {
+    let mut first = [0, 1, 2];
+    let mut second = [2, 1, 3];
+    let mut faces = [
+        aiFace { mNumIndices: 3, mIndices: first.as_mut_ptr() },
+        aiFace { mNumIndices: 0, mIndices: std::ptr::null_mut() },
+        aiFace { mNumIndices: 3, mIndices: second.as_mut_ptr() },
+    ];
+
+    let mut mesh: aiMesh = unsafe { std::mem::zeroed() };
+    mesh.mNumFaces = 3;
+    mesh.mFaces = faces.as_mut_ptr();
+    assert_eq!(mesh.all_indices(), [0, 1, 2, 2, 1, 3]);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::os::raw::c_void;
+use std::ptr;
+
+fn entry<T>(tag: aiMetadataType, value: &mut T) -> aiMetadataEntry {
+    aiMetadataEntry { mType: tag, mData: value as *mut T as *mut c_void }
+}
+
+fn with_metadata(keys: &[&str], entries: &mut [aiMetadataEntry], check: impl FnOnce(&aiMetadata)) {
+    let mut keys: Vec<aiString> = keys.iter().map(|key| aiString::from_bytes(key.as_bytes()).unwrap()).collect();
+    let metadata = aiMetadata {
+        mNumProperties: keys.len() as u32,
+        mKeys: keys.as_mut_ptr(),
+        mValues: entries.as_mut_ptr(),
+    };
+    check(&metadata);
+}
+
+#[test]
+fn decodes_by_type() {
+    let mut flag = 1u8;
+    let mut scale = 2.54f32;
+    let mut up = 1i32;
+    let mut name = aiString::from_bytes(b"Blender").unwrap();
+    let mut offset = aiVector3D { x: 1.0, y: 2.0, z: 3.0 };
+    let mut size = -5i64;
+    let mut entries = [
+        entry(aiMetadataType::AI_BOOL, &mut flag),
+        entry(aiMetadataType::AI_FLOAT, &mut scale),
+        entry(aiMetadataType::AI_INT32, &mut up),
+        entry(aiMetadataType::AI_AISTRING, &mut name),
+        entry(aiMetadataType::AI_AIVECTOR3D, &mut offset),
+        entry(aiMetadataType(8 as _), &mut size),
+    ];
+    let keys = ["Visible", "UnitScaleFactor", "UpAxis", "Creator", "Offset", "Size"];
+
+    with_metadata(&keys, &mut entries, |metadata| {
+        let entries: Vec<_> = metadata.entries().collect();
+        assert_eq!(
+            entries,
+            [
+                ("Visible".to_owned(), MetadataValue::Bool(true)),
+                ("UnitScaleFactor".to_owned(), MetadataValue::Float(2.54)),
+                ("UpAxis".to_owned(), MetadataValue::Int32(1)),
+                ("Creator".to_owned(), MetadataValue::String("Blender".to_owned())),
+                ("Offset".to_owned(), MetadataValue::Vector3D(offset)),
+                ("Size".to_owned(), MetadataValue::Int64(-5)),
+            ]
+        );
+        assert_eq!(metadata.get("UpAxis"), Some(MetadataValue::Int32(1)));
+        assert_eq!(metadata.get("FrontAxis"), None);
+    });
+}
+
+#[test]
+fn nested_and_unknown() {
+    let mut inner_value = 7u32;
+    let mut inner_entries = [entry(aiMetadataType(9 as _), &mut inner_value)];
+    let mut inner_keys = [aiString::from_bytes(b"Count").unwrap()];
+    let mut inner = aiMetadata {
+        mNumProperties: 1,
+        mKeys: inner_keys.as_mut_ptr(),
+        mValues: inner_entries.as_mut_ptr(),
+    };
+    let mut unknown = 0u64;
+    let mut entries = [
+        entry(aiMetadataType(7 as _), &mut inner),
+        entry(aiMetadataType(42 as _), &mut unknown),
+        aiMetadataEntry { mType: aiMetadataType::AI_INT32, mData: ptr::null_mut() },
+    ];
+
+    with_metadata(&["Extras", "Future", "Missing"], &mut entries, |metadata| {
+        assert_eq!(
+            metadata.get("Extras"),
+            Some(MetadataValue::Metadata(vec![("Count".to_owned(), MetadataValue::UInt32(7))]))
+        );
+        assert_eq!(metadata.get("Future"), Some(MetadataValue::Unknown(42)));
+        assert_eq!(metadata.get("Missing"), Some(MetadataValue::Unknown(1)));
+    });
+}
+
+#[test]
+fn no_values() {
+    let mut keys = [aiString::from_bytes(b"Orphan").unwrap()];
+    let metadata = aiMetadata { mNumProperties: 1, mKeys: keys.as_mut_ptr(), mValues: ptr::null_mut() };
+    assert_eq!(metadata.entries().count(), 0);
+    assert_eq!(metadata.get("Orphan"), None);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "mint")]
+
+extern crate assimp_sys;
+extern crate mint;
+
+use assimp_sys::*;
+
+fn sequential_matrix() -> aiMatrix4x4 {
+    aiMatrix4x4 {
+        a1: 1.0,
+        a2: 2.0,
+        a3: 3.0,
+        a4: 4.0,
+        b1: 5.0,
+        b2: 6.0,
+        b3: 7.0,
+        b4: 8.0,
+        c1: 9.0,
+        c2: 10.0,
+        c3: 11.0,
+        c4: 12.0,
+        d1: 13.0,
+        d2: 14.0,
+        d3: 15.0,
+        d4: 16.0,
+    }
+}
+
+#[test]
+fn matrix_rows() {
+    let rows: mint::RowMatrix4<ai_real> = sequential_matrix().into();
+    assert_eq!(rows.x, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+    assert_eq!(rows.w, mint::Vector4 { x: 13.0, y: 14.0, z: 15.0, w: 16.0 });
+    assert_eq!(aiMatrix4x4::from(rows), sequential_matrix());
+}
+
+#[test]
+fn matrix_columns() {
+    let columns: mint::ColumnMatrix4<ai_real> = sequential_matrix().into();
+    assert_eq!(columns.x, mint::Vector4 { x: 1.0, y: 5.0, z: 9.0, w: 13.0 });
+    assert_eq!(columns.w, mint::Vector4 { x: 4.0, y: 8.0, z: 12.0, w: 16.0 });
+    assert_eq!(aiMatrix4x4::from(columns), sequential_matrix());
+}
+
+#[test]
+fn quaternion_order() {
+    let q = aiQuaternion { w: 1.0, x: 2.0, y: 3.0, z: 4.0 };
+    let converted: mint::Quaternion<ai_real> = q.into();
+    assert_eq!(converted.s, 1.0);
+    assert_eq!(converted.v, mint::Vector3 { x: 2.0, y: 3.0, z: 4.0 });
+    assert_eq!(aiQuaternion::from(converted), q);
+}
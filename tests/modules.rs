@@ -0,0 +1,21 @@
+extern crate assimp_sys;
+
+use assimp_sys::{material, mesh, scene, texture};
+
+// The submodules only re-export, so their items are the very same as the ones at the root.
+fn same<T>(_: Option<T>, _: Option<T>) {}
+
+#[test]
+fn reexports_match_root() {
+    same(None::<material::aiMaterial>, None::<assimp_sys::aiMaterial>);
+    same(None::<mesh::aiMesh>, None::<assimp_sys::aiMesh>);
+    same(None::<scene::aiScene>, None::<assimp_sys::aiScene>);
+    same(None::<texture::aiTexel>, None::<assimp_sys::aiTexel>);
+}
+
+#[test]
+fn longest_prefix_wins() {
+    // `aiTextureType` would match `texture`'s `aiTexture` as well.
+    same(None::<material::aiTextureType>, None::<assimp_sys::aiTextureType>);
+    assert_eq!(mesh::AI_MAX_NUMBER_OF_COLOR_SETS, assimp_sys::AI_MAX_NUMBER_OF_COLOR_SETS);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::fs;
+
+#[test]
+fn reports_progress() {
+    let path = std::env::temp_dir().join("assimp-sys-progress-triangle.obj");
+    fs::write(&path, "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+
+    let mut updates = Vec::new();
+    let flags = aiPostProcessSteps_aiProcess_Triangulate | aiPostProcessSteps_aiProcess_GenNormals;
+    let scene = import_file_with_progress(path.to_str().unwrap(), flags, |progress| {
+        updates.push(progress);
+        true
+    })
+    .unwrap();
+
+    assert_eq!(scene.as_ref().mNumMeshes, 1);
+    assert!(!updates.is_empty());
+    assert!(updates.iter().all(|&progress| progress <= 1.0));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_file_with_progress() {
+    let error = import_file_with_progress("does/not/exist.obj", 0, |_| true).unwrap_err();
+    assert!(!error.is_empty());
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::fs;
+
+#[test]
+fn split_by_triangle_limit() {
+    let path = std::env::temp_dir().join("assimp-sys-properties-quad.obj");
+    fs::write(&path, "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3\nf 1 3 4\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let mut properties = PropertyStore::new();
+    properties.set_int("PP_SLM_TRIANGLE_LIMIT", 1).unwrap();
+    let flags = aiPostProcessSteps_aiProcess_SplitLargeMeshes;
+
+    assert_eq!(import_file(path, flags).unwrap().as_ref().mNumMeshes, 1);
+    assert_eq!(properties.import_file(path, flags).unwrap().as_ref().mNumMeshes, 2);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn invalid_names_and_values() {
+    let mut properties = PropertyStore::new();
+    assert!(properties.set_bool("FAVOUR\0SPEED", true).is_err());
+    assert!(properties.set_string("PP_OG_EXCLUDE_LIST", &"x".repeat(MAXLEN as usize)).is_err());
+    assert!(properties.set_string("PP_OG_EXCLUDE_LIST", "keep").is_ok());
+}
@@ -0,0 +1,19 @@
+extern crate assimp_sys;
+
+use assimp_sys::{ai_real, aiVector3D};
+
+// Only type checks if the fields are `ai_real`, whichever precision that is.
+fn length(v: aiVector3D) -> ai_real {
+    (v.x * v.x + v.y * v.y + v.z * v.z).sqrt()
+}
+
+#[test]
+fn vectors_use_ai_real() {
+    assert_eq!(length(aiVector3D { x: 3.0, y: 0.0, z: 4.0 }), 5.0);
+}
+
+#[test]
+fn precision_follows_feature() {
+    let expected = if cfg!(feature = "double-precision") { 8 } else { 4 };
+    assert_eq!(std::mem::size_of::<ai_real>(), expected);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+
+#[test]
+fn success_is_ok() {
+    assert_eq!(AiReturn::from(aiReturn::aiReturn_SUCCESS).into_result(), Ok(()));
+}
+
+#[test]
+fn errors() {
+    let failure = AiReturn::from(aiReturn::aiReturn_FAILURE).into_result();
+    let out_of_memory = AiReturn::from(aiReturn::aiReturn_OUTOFMEMORY).into_result();
+    assert_eq!(failure, Err(AiError::Failure));
+    assert_eq!(out_of_memory, Err(AiError::OutOfMemory));
+    assert!(AiError::OutOfMemory.to_string().contains("aiReturn_OUTOFMEMORY"));
+}
+
+#[test]
+fn round_trip() {
+    for value in [AiReturn::Success, AiReturn::Failure, AiReturn::OutOfMemory].iter() {
+        assert_eq!(AiReturn::from(aiReturn::from(*value)), *value);
+    }
+}
+
+#[test]
+fn unknown_code_is_failure() {
+    assert_eq!(AiReturn::from(aiReturn(-2)), AiReturn::Failure);
+}
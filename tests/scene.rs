@@ -0,0 +1,135 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::ptr;
+
+#[test]
+fn null_scene() {
+    assert!(unsafe { Scene::from_raw(ptr::null()) }.is_none());
+}
+
+// A scene assimp didn't allocate, which must never reach `aiReleaseImport`.
+fn with_fake_scene(fill: impl FnOnce(&mut aiScene), check: impl FnOnce(&Scene)) {
+    let mut raw: aiScene = unsafe { std::mem::zeroed() };
+    fill(&mut raw);
+    let scene = unsafe { Scene::from_raw(&raw) }.unwrap();
+    check(&scene);
+    std::mem::forget(scene);
+}
+
+// A fake scene with the nodes 0 -> (1 -> 3), 2, each node's `mNumMeshes` set to its index so
+// the order they're visited in can be checked.
+fn with_fake_tree(transforms: [aiMatrix4x4; 4], check: impl FnOnce(&Scene)) {
+    let mut nodes: Vec<aiNode> = (0..4).map(|_| unsafe { std::mem::zeroed() }).collect();
+    for (i, (node, transform)) in nodes.iter_mut().zip(transforms).enumerate() {
+        node.mNumMeshes = i as u32;
+        node.mTransformation = transform;
+    }
+    let base = nodes.as_mut_ptr();
+    let mut root_children = unsafe { [base.add(1), base.add(2)] };
+    let mut child_children = unsafe { [base.add(3)] };
+    nodes[0].mNumChildren = 2;
+    nodes[0].mChildren = root_children.as_mut_ptr();
+    nodes[1].mNumChildren = 1;
+    nodes[1].mChildren = child_children.as_mut_ptr();
+
+    with_fake_scene(|raw| raw.mRootNode = base, check);
+}
+
+#[test]
+fn empty_scene_iterators() {
+    with_fake_scene(
+        |_| {},
+        |scene| {
+            assert_eq!(scene.meshes().count(), 0);
+            assert_eq!(scene.materials().count(), 0);
+            assert_eq!(scene.nodes().count(), 0);
+            assert_eq!(scene.embedded_textures().count(), 0);
+        },
+    );
+}
+
+#[test]
+fn count_without_array_is_empty() {
+    with_fake_scene(
+        |raw| raw.mNumMeshes = 3,
+        |scene| assert_eq!(scene.meshes().count(), 0),
+    );
+}
+
+#[test]
+fn nodes_are_depth_first() {
+    with_fake_tree([aiMatrix4x4::identity(); 4], |scene| {
+        let order: Vec<u32> = scene.nodes().map(|node| node.mNumMeshes).collect();
+        assert_eq!(order, [0, 1, 3, 2]);
+    });
+}
+
+#[test]
+fn embedded_textures() {
+    let mut textures: Vec<aiTexture> = (0..2).map(|_| unsafe { std::mem::zeroed() }).collect();
+    textures[0].mWidth = 10;
+    textures[1].mWidth = 20;
+    let base = textures.as_mut_ptr();
+    // A null entry is skipped.
+    let mut pointers = unsafe { [base, ptr::null_mut(), base.add(1)] };
+
+    with_fake_scene(
+        |raw| {
+            raw.mNumTextures = 3;
+            raw.mTextures = pointers.as_mut_ptr();
+        },
+        |scene| {
+            let widths: Vec<u32> = scene.embedded_textures().map(|texture| texture.mWidth).collect();
+            assert_eq!(widths, [10, 20]);
+        },
+    );
+}
+
+fn translation(x: ai_real) -> aiMatrix4x4 {
+    let mut matrix = aiMatrix4x4::identity();
+    matrix.a4 = x;
+    matrix
+}
+
+#[test]
+fn walk_nodes_accumulates_transforms() {
+    let transforms = [translation(1.0), translation(2.0), translation(3.0), translation(4.0)];
+    with_fake_tree(transforms, |scene| {
+        let mut visited = Vec::new();
+        scene.walk_nodes(|node, world| visited.push((node.mNumMeshes, world.a4)));
+        assert_eq!(visited, [(0, 1.0), (1, 3.0), (3, 7.0), (2, 4.0)]);
+    });
+}
+
+#[test]
+fn walk_nodes_without_root() {
+    with_fake_scene(
+        |_| {},
+        |scene| scene.walk_nodes(|_, _| panic!("there are no nodes")),
+    );
+}
+
+#[test]
+fn walk_nodes_stops_at_cycles() {
+    let mut nodes: Vec<aiNode> = (0..2).map(|_| unsafe { std::mem::zeroed() }).collect();
+    // 0 -> 1 -> 0
+    let base = nodes.as_mut_ptr();
+    let mut root_children = unsafe { [base.add(1)] };
+    let mut child_children = [base];
+    nodes[0].mNumChildren = 1;
+    nodes[0].mChildren = root_children.as_mut_ptr();
+    nodes[1].mNumChildren = 1;
+    nodes[1].mChildren = child_children.as_mut_ptr();
+
+    with_fake_scene(
+        |raw| raw.mRootNode = base,
+        |scene| {
+            let mut count = 0;
+            scene.walk_nodes(|_, _| count += 1);
+            assert_eq!(count, 2);
+        },
+    );
+}
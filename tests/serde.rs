@@ -0,0 +1,34 @@
+#![cfg(feature = "serde")]
+
+extern crate assimp_sys;
+extern crate serde_json;
+
+use assimp_sys::*;
+
+#[test]
+fn vector_fields() {
+    let v = aiVector3D { x: 1.0, y: 2.0, z: 3.0 };
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"z":3.0}"#);
+    assert_eq!(serde_json::from_str::<aiVector3D>(&json).unwrap(), v);
+}
+
+#[test]
+fn quaternion_round_trip() {
+    let q = aiQuaternion { w: 1.0, x: 2.0, y: 3.0, z: 4.0 };
+    let json = serde_json::to_string(&q).unwrap();
+    assert_eq!(serde_json::from_str::<aiQuaternion>(&json).unwrap(), q);
+}
+
+#[test]
+fn string_as_str() {
+    let string: aiString = serde_json::from_str(r#""mesh_01""#).unwrap();
+    assert_eq!(string.length, 7);
+    assert_eq!(serde_json::to_string(&string).unwrap(), r#""mesh_01""#);
+}
+
+#[test]
+fn string_too_long() {
+    let json = serde_json::to_string(&"x".repeat(MAXLEN as usize)).unwrap();
+    assert!(serde_json::from_str::<aiString>(&json).is_err());
+}
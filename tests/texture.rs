@@ -0,0 +1,47 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::ptr;
+
+fn texture(width: u32, height: u32, data: *mut aiTexel, hint: &[u8]) -> aiTexture {
+    let mut texture: aiTexture = unsafe { std::mem::zeroed() };
+    texture.mWidth = width;
+    texture.mHeight = height;
+    texture.pcData = data;
+    for (dst, &src) in texture.achFormatHint.iter_mut().zip(hint) {
+        *dst = src as _;
+    }
+    texture
+}
+
+#[test]
+fn compressed() {
+    let mut png = *b"\x89PNG\r\n\x1a\n";
+    let texture = texture(png.len() as u32, 0, png.as_mut_ptr() as *mut aiTexel, b"png");
+    assert_eq!(texture.data(), TextureData::Compressed(b"\x89PNG\r\n\x1a\n"));
+    assert_eq!(texture.format_hint(), "png");
+}
+
+#[test]
+fn raw() {
+    let mut texels: Vec<aiTexel> = (0..6).map(|i| aiTexel { b: i, g: 0, r: 0, a: 255 }).collect();
+    let texture = texture(3, 2, texels.as_mut_ptr(), b"argb8888");
+    match texture.data() {
+        TextureData::Raw { width, height, texels: data } => {
+            assert_eq!((width, height), (3, 2));
+            assert_eq!(data, &texels[..]);
+        }
+        data => panic!("expected raw texels, got {:?}", data),
+    }
+    assert_eq!(texture.format_hint(), "argb8888");
+}
+
+#[test]
+fn missing_data() {
+    assert_eq!(texture(16, 0, ptr::null_mut(), b"").data(), TextureData::Compressed(&[]));
+    let raw = texture(4, 4, ptr::null_mut(), b"");
+    assert_eq!(raw.data(), TextureData::Raw { width: 4, height: 4, texels: &[] });
+    assert_eq!(raw.format_hint(), "");
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "helpers")]
+
+extern crate assimp_sys;
+
+use assimp_sys::*;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn vertices_move_to_other_threads() {
+    let vertices = [aiVector3D { x: 1.0, y: 2.0, z: 2.0 }, aiVector3D { x: 0.0, y: 3.0, z: 4.0 }];
+    let lengths = thread::spawn(move || vertices.iter().map(|v| v.length()).collect::<Vec<_>>());
+    assert_eq!(lengths.join().unwrap(), [3.0, 5.0]);
+}
+
+#[test]
+fn strings_are_shared_between_threads() {
+    let name = Arc::new(aiString::from_bytes(b"node").unwrap());
+    let shared = Arc::clone(&name);
+    let copy = thread::spawn(move || *shared).join().unwrap();
+    assert_eq!(copy, *name);
+}
@@ -1,10 +1,18 @@
+#![cfg(not(feature = "dynamic-loading"))]
+
 extern crate assimp_sys;
 use assimp_sys::*;
 
 #[test]
 fn check_version() {
+    // Any 5.x is accepted, see `range_version` in build.rs.
     let major = unsafe { aiGetVersionMajor() };
-    let minor = unsafe { aiGetVersionMinor() };
     assert_eq!(major, 5);
-    assert_eq!(minor, 0);
+}
+
+#[test]
+fn runtime_version() {
+    let (major, minor, _) = version();
+    assert_eq!((major, minor), unsafe { (aiGetVersionMajor(), aiGetVersionMinor()) });
+    assert!(legal_string().contains("Open Asset Import Library"));
 }